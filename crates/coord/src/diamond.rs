@@ -0,0 +1,124 @@
+use std::ops::RangeInclusive;
+
+use crate::Point2;
+
+/// All points within a Manhattan distance of `radius` from `center`, which looks like a diamond.
+///
+/// # Examples
+///
+/// ```
+/// use coord::{Diamond, Point2};
+///
+/// // The range of a sensor at (8, 7) whose closest beacon is at (2, 10)
+/// let sensor = Diamond::through(Point2::new(8, 7), Point2::new(2, 10));
+///
+/// assert_eq!(sensor.radius, 9);
+/// assert!(sensor.contains(Point2::new(8, -2)));
+/// assert_eq!(sensor.row(10), Some(2..=14));
+/// assert_eq!(sensor.row(17), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Diamond {
+    pub center: Point2<i64>,
+    pub radius: u64,
+}
+
+impl Diamond {
+    #[must_use]
+    pub const fn new(center: Point2<i64>, radius: u64) -> Self {
+        Self { center, radius }
+    }
+
+    /// The smallest diamond around `center` that contains `point`.
+    #[must_use]
+    pub fn through(center: Point2<i64>, point: Point2<i64>) -> Self {
+        Self::new(center, center.manhattan_distance(&point))
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: Point2<i64>) -> bool {
+        self.center.manhattan_distance(&point) <= self.radius
+    }
+
+    /// The columns this diamond covers on row `y`, if any.
+    #[must_use]
+    pub fn row(&self, y: i64) -> Option<RangeInclusive<i64>> {
+        let half_width = self.radius.checked_sub(self.center.y.abs_diff(y))? as i64;
+
+        Some((self.center.x - half_width)..=(self.center.x + half_width))
+    }
+
+    /// Number of lattice points in the diamond.
+    #[must_use]
+    pub fn lattice_points(&self) -> u64 {
+        2 * self.radius * (self.radius + 1) + 1
+    }
+}
+
+/// The columns covered by any of `diamonds` on row `y`, as sorted ranges that neither overlap nor
+/// touch.
+///
+/// # Examples
+///
+/// ```
+/// use coord::{Diamond, Point2, row_coverage};
+///
+/// let diamonds = [
+///     Diamond::new(Point2::new(0, 0), 2),
+///     Diamond::new(Point2::new(3, 1), 1),
+///     Diamond::new(Point2::new(10, 0), 3),
+/// ];
+///
+/// assert_eq!(row_coverage(&diamonds, 1), vec![-1..=4, 8..=12]);
+/// ```
+pub fn row_coverage<'a, I>(diamonds: I, y: i64) -> Vec<RangeInclusive<i64>>
+where
+    I: IntoIterator<Item = &'a Diamond>,
+{
+    let mut ranges = diamonds
+        .into_iter()
+        .filter_map(|diamond| diamond.row(y))
+        .collect::<Vec<_>>();
+    ranges.sort_unstable_by_key(|range| *range.start());
+
+    let mut merged: Vec<RangeInclusive<i64>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if *range.start() <= last.end() + 1 => {
+                if range.end() > last.end() {
+                    *last = *last.start()..=*range.end();
+                }
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diamond() {
+        let diamond = Diamond::new(Point2::new(2, 3), 2);
+
+        assert_eq!(diamond.lattice_points(), 13);
+        assert_eq!(
+            (-1..=7)
+                .filter_map(|y| diamond.row(y))
+                .map(|row| row.count())
+                .sum::<usize>(),
+            13
+        );
+        assert!(diamond.contains(Point2::new(3, 4)));
+        assert!(!diamond.contains(Point2::new(4, 4)));
+
+        // Touching ranges are merged
+        let diamonds = [diamond, Diamond::new(Point2::new(6, 3), 1)];
+        assert_eq!(row_coverage(&diamonds, 3), vec![0..=7]);
+        assert_eq!(row_coverage(&diamonds, 4), vec![1..=3, 6..=6]);
+        assert_eq!(row_coverage(&diamonds, 9), vec![]);
+    }
+}
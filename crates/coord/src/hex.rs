@@ -0,0 +1,366 @@
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::Point2;
+
+/// A hexagon in axial coordinates.
+///
+/// The third cube coordinate is implied by `q + r + s == 0`, see [`Hex::s`]. Axial coordinates
+/// work the same for both flat-topped and pointy-topped hexagons; only the names of the
+/// directions differ, see [`FlatDirection`] and [`PointyDirection`].
+///
+/// # Examples
+///
+/// ```
+/// use coord::{FlatDirection, Hex};
+///
+/// let path = "ne,ne,s,s".split(',').map(|step| step.parse::<FlatDirection>().unwrap());
+/// let end = path.fold(Hex::default(), |hex, direction| hex + direction);
+///
+/// assert_eq!(end, Hex::new(2, 0));
+/// assert_eq!(end.distance(&Hex::default()), 2);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+/// How hexagons are laid out on a rectangular grid, by shifting every other row or column by
+/// half a hexagon.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum OffsetLayout {
+    /// Pointy-topped hexagons, with odd rows shifted right.
+    OddRows,
+    /// Pointy-topped hexagons, with even rows shifted right.
+    EvenRows,
+    /// Flat-topped hexagons, with odd columns shifted down.
+    OddColumns,
+    /// Flat-topped hexagons, with even columns shifted down.
+    EvenColumns,
+}
+
+impl Hex {
+    /// The offsets to the six neighbours, counter-clockwise.
+    pub const NEIGHBOR_OFFSETS: [Hex; 6] = [
+        Hex::new(1, 0),
+        Hex::new(1, -1),
+        Hex::new(0, -1),
+        Hex::new(-1, 0),
+        Hex::new(-1, 1),
+        Hex::new(0, 1),
+    ];
+
+    #[must_use]
+    pub const fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    /// Create a hexagon from cube coordinates, if they add up to zero.
+    #[must_use]
+    pub const fn from_cube(q: i64, r: i64, s: i64) -> Option<Self> {
+        if q + r + s == 0 {
+            Some(Self::new(q, r))
+        } else {
+            None
+        }
+    }
+
+    /// The third cube coordinate.
+    #[must_use]
+    pub const fn s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    #[must_use]
+    pub const fn to_cube(self) -> (i64, i64, i64) {
+        (self.q, self.r, self.s())
+    }
+
+    pub fn neighbors(self) -> impl Iterator<Item = Hex> {
+        Self::NEIGHBOR_OFFSETS
+            .into_iter()
+            .map(move |offset| self + offset)
+    }
+
+    /// The number of steps between two hexagons.
+    #[must_use]
+    pub fn distance(&self, other: &Self) -> u64 {
+        let (dq, dr, ds) = (*self - *other).to_cube();
+
+        dq.unsigned_abs()
+            .max(dr.unsigned_abs())
+            .max(ds.unsigned_abs())
+    }
+
+    /// Convert into `(column, row)` coordinates of a rectangular grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use coord::{Hex, OffsetLayout, Point2, PointyDirection};
+    ///
+    /// let hex = Hex::default() + PointyDirection::SouthEast;
+    /// let offset = hex.to_offset(OffsetLayout::EvenRows);
+    ///
+    /// assert_eq!(offset, Point2::new(1, 1));
+    /// assert_eq!(Hex::from_offset(offset, OffsetLayout::EvenRows), hex);
+    /// ```
+    #[must_use]
+    pub fn to_offset(self, layout: OffsetLayout) -> Point2<i64> {
+        let Hex { q, r } = self;
+
+        match layout {
+            OffsetLayout::OddRows => Point2::new(q + (r - (r & 1)) / 2, r),
+            OffsetLayout::EvenRows => Point2::new(q + (r + (r & 1)) / 2, r),
+            OffsetLayout::OddColumns => Point2::new(q, r + (q - (q & 1)) / 2),
+            OffsetLayout::EvenColumns => Point2::new(q, r + (q + (q & 1)) / 2),
+        }
+    }
+
+    /// Convert from `(column, row)` coordinates of a rectangular grid.
+    #[must_use]
+    pub fn from_offset(Point2 { x, y }: Point2<i64>, layout: OffsetLayout) -> Self {
+        match layout {
+            OffsetLayout::OddRows => Hex::new(x - (y - (y & 1)) / 2, y),
+            OffsetLayout::EvenRows => Hex::new(x - (y + (y & 1)) / 2, y),
+            OffsetLayout::OddColumns => Hex::new(x, y - (x - (x & 1)) / 2),
+            OffsetLayout::EvenColumns => Hex::new(x, y - (x + (x & 1)) / 2),
+        }
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl SubAssign for Hex {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Hex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Hex::new(-self.q, -self.r)
+    }
+}
+
+impl Mul<i64> for Hex {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Hex::new(self.q * factor, self.r * factor)
+    }
+}
+
+/// Error returned when parsing a hex direction.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseHexDirectionError(pub String);
+
+impl Display for ParseHexDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown hex direction: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseHexDirectionError {}
+
+macro_rules! hex_direction {
+    (
+        $(#[$meta:meta])*
+        $name:ident { $($variant:ident = $text:literal => ($q:literal, $r:literal)),+ $(,)? }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+        pub enum $name {
+            $($variant),+
+        }
+
+        impl $name {
+            /// All six directions, clockwise.
+            pub const ALL: [Self; 6] = [$(Self::$variant),+];
+
+            /// The offset of a single step in this direction.
+            #[must_use]
+            pub const fn offset(self) -> Hex {
+                match self {
+                    $(Self::$variant => Hex::new($q, $r)),+
+                }
+            }
+
+            #[must_use]
+            pub const fn opposite(self) -> Self {
+                self.rotate_clockwise(3)
+            }
+
+            /// Rotate clockwise by `steps` sixths of a turn.
+            #[must_use]
+            pub const fn rotate_clockwise(self, steps: usize) -> Self {
+                Self::ALL[(self as usize + steps) % 6]
+            }
+
+            /// Parse directions that aren't separated, like `nwwswee`.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error containing the rest of `s` if it doesn't start with a direction.
+            pub fn parse_run(mut s: &str) -> Result<Vec<Self>, ParseHexDirectionError> {
+                let mut directions = vec![];
+
+                while !s.is_empty() {
+                    let (direction, rest) = [2, 1]
+                        .into_iter()
+                        .filter_map(|len| Some((s.get(..len)?.parse().ok()?, &s[len..])))
+                        .next()
+                        .ok_or_else(|| ParseHexDirectionError(s.to_string()))?;
+                    directions.push(direction);
+                    s = rest;
+                }
+
+                Ok(directions)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseHexDirectionError;
+
+            /// Parse a compass abbreviation like `ne`, ignoring case.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.to_ascii_lowercase().as_str() {
+                    $($text => Ok(Self::$variant),)+
+                    _ => Err(ParseHexDirectionError(s.to_string())),
+                }
+            }
+        }
+
+        impl From<$name> for Hex {
+            fn from(direction: $name) -> Self {
+                direction.offset()
+            }
+        }
+
+        impl Add<$name> for Hex {
+            type Output = Self;
+
+            fn add(self, direction: $name) -> Self {
+                self + direction.offset()
+            }
+        }
+
+        impl AddAssign<$name> for Hex {
+            fn add_assign(&mut self, direction: $name) {
+                *self += direction.offset();
+            }
+        }
+    };
+}
+
+hex_direction!(
+    /// A direction between flat-topped hexagons, which are stacked in columns.
+    FlatDirection {
+        North = "n" => (0, -1),
+        NorthEast = "ne" => (1, -1),
+        SouthEast = "se" => (1, 0),
+        South = "s" => (0, 1),
+        SouthWest = "sw" => (-1, 1),
+        NorthWest = "nw" => (-1, 0),
+    }
+);
+
+hex_direction!(
+    /// A direction between pointy-topped hexagons, which are lined up in rows.
+    PointyDirection {
+        East = "e" => (1, 0),
+        SouthEast = "se" => (0, 1),
+        SouthWest = "sw" => (-1, 1),
+        West = "w" => (-1, 0),
+        NorthWest = "nw" => (0, -1),
+        NorthEast = "ne" => (1, -1),
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex() {
+        let hex = Hex::from_cube(2, -3, 1).unwrap();
+        assert_eq!(Hex::from_cube(1, 1, 1), None);
+        assert_eq!(hex.distance(&Hex::default()), 3);
+        assert_eq!(hex.neighbors().filter(|n| n.distance(&hex) == 1).count(), 6);
+
+        // Each neighbour is a step in one of the directions, in either orientation
+        for direction in FlatDirection::ALL {
+            assert!(hex.neighbors().any(|n| n == hex + direction));
+            assert_eq!(
+                direction.offset() + direction.opposite().offset(),
+                Hex::default()
+            );
+        }
+        for direction in PointyDirection::ALL {
+            assert!(hex.neighbors().any(|n| n == hex + direction));
+            assert_eq!(direction.rotate_clockwise(6), direction);
+        }
+
+        assert_eq!(
+            PointyDirection::parse_run("nwwswee"),
+            Ok(vec![
+                PointyDirection::NorthWest,
+                PointyDirection::West,
+                PointyDirection::SouthWest,
+                PointyDirection::East,
+                PointyDirection::East,
+            ])
+        );
+        assert_eq!(
+            FlatDirection::parse_run("nex"),
+            Err(ParseHexDirectionError("x".to_string()))
+        );
+        assert_eq!("SW".parse(), Ok(FlatDirection::SouthWest));
+
+        for layout in [
+            OffsetLayout::OddRows,
+            OffsetLayout::EvenRows,
+            OffsetLayout::OddColumns,
+            OffsetLayout::EvenColumns,
+        ] {
+            for q in -3..=3 {
+                for r in -3..=3 {
+                    let hex = Hex::new(q, r);
+                    assert_eq!(Hex::from_offset(hex.to_offset(layout), layout), hex);
+                }
+            }
+        }
+        assert_eq!(
+            (Hex::new(-1, 0) + FlatDirection::SouthEast).to_offset(OffsetLayout::OddColumns),
+            Point2::new(0, 0)
+        );
+        assert_eq!(
+            (Hex::new(1, 0) + FlatDirection::SouthEast).to_offset(OffsetLayout::OddColumns),
+            Point2::new(2, 1)
+        );
+    }
+}
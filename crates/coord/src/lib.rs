@@ -1,29 +1,14 @@
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub struct Coordinate2D {
-    pub x: i64,
-    pub y: i64,
-}
-
-impl Coordinate2D {
-    #[must_use] pub const fn new(x: i64, y: i64) -> Self {
-        Self { x, y }
-    }
-
-    /// Return the manhattan distance from `self` to `other`.
-    ///
-    /// The manhattan distance is the sum of the `x` and `y` distances.
-    #[must_use] pub fn manhattan_distance(&self, other: &Self) -> u64 {
-        let x_distance = self.x.abs_diff(other.x);
-        let y_distance = self.y.abs_diff(other.y);
-
-        x_distance + y_distance
-    }
-
-    /// Translate by `other`.
-    #[must_use] pub fn translate(&self, other: &Self) -> Self {
-        Self {
-            x: self.x + other.x,
-            y: self.y + other.y,
-        }
-    }
-}
+mod diamond;
+mod hex;
+mod point;
+mod polygon;
+mod segment;
+
+pub use diamond::{Diamond, row_coverage};
+pub use hex::{FlatDirection, Hex, OffsetLayout, ParseHexDirectionError, PointyDirection};
+pub use point::{Component, Point2, Point3};
+pub use polygon::Polygon;
+pub use segment::{Intersection, Segment};
+
+/// A point on a plane with signed coordinates, e.g. on an unbounded map.
+pub type Coordinate2D = Point2<i64>;
@@ -0,0 +1,275 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A primitive integer type that can be used as a point component.
+pub trait Component: Copy + Ord {
+    /// The unsigned type distances are measured in.
+    type Distance: Copy + Ord + Add<Output = Self::Distance> + Mul<Output = Self::Distance>;
+
+    fn abs_diff(self, other: Self) -> Self::Distance;
+}
+
+macro_rules! impl_component {
+    ($($t:ty => $distance:ty),*) => {
+        $(
+            impl Component for $t {
+                type Distance = $distance;
+
+                fn abs_diff(self, other: Self) -> $distance {
+                    <$t>::abs_diff(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_component!(
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize
+);
+
+/// A point (or vector) in 2D space.
+///
+/// Points are ordered by `x` first, then by `y`.
+///
+/// # Examples
+///
+/// ```
+/// use coord::Point2;
+///
+/// let a = Point2::new(1_i64, 2);
+/// let b = Point2::from((4, -2));
+///
+/// assert_eq!(a + b * 2, Point2::new(9, -2));
+/// assert_eq!(-(b - a), Point2::new(-3, 4));
+/// assert_eq!(a.manhattan_distance(&b), 7);
+/// assert_eq!(a.chebyshev_distance(&b), 4);
+/// assert_eq!(a.squared_euclidean_distance(&b), 25);
+/// assert_eq!(<(i64, i64)>::from(a), (1, 2));
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point (or vector) in 3D space.
+///
+/// Points are ordered by `x` first, then by `y`, then by `z`.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    #[must_use]
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Translate by `other`.
+    #[must_use]
+    pub fn translate(&self, other: &Self) -> Self
+    where
+        T: Copy + Add<Output = T>,
+    {
+        *self + *other
+    }
+
+    /// Convert into `(column, row)` coordinates of a grid, if neither component is negative (or
+    /// too large).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use coord::Point2;
+    ///
+    /// assert_eq!(Point2::new(3_i64, 4).to_grid_coord(), Some((3, 4)));
+    /// assert_eq!(Point2::new(3_i64, -1).to_grid_coord(), None);
+    /// ```
+    #[must_use]
+    pub fn to_grid_coord(self) -> Option<(usize, usize)>
+    where
+        T: TryInto<usize>,
+    {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+
+    /// Convert from `(column, row)` coordinates of a grid, if they fit into `T`.
+    #[must_use]
+    pub fn from_grid_coord((column, row): (usize, usize)) -> Option<Self>
+    where
+        T: TryFrom<usize>,
+    {
+        Some(Self::new(column.try_into().ok()?, row.try_into().ok()?))
+    }
+}
+
+impl<T> Point3<T> {
+    #[must_use]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Convert into `(x, y, z)` coordinates of a grid, if no component is negative (or too large).
+    #[must_use]
+    pub fn to_grid_coord(self) -> Option<(usize, usize, usize)>
+    where
+        T: TryInto<usize>,
+    {
+        Some((
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+            self.z.try_into().ok()?,
+        ))
+    }
+
+    /// Convert from `(x, y, z)` coordinates of a grid, if they fit into `T`.
+    #[must_use]
+    pub fn from_grid_coord((x, y, z): (usize, usize, usize)) -> Option<Self>
+    where
+        T: TryFrom<usize>,
+    {
+        Some(Self::new(
+            x.try_into().ok()?,
+            y.try_into().ok()?,
+            z.try_into().ok()?,
+        ))
+    }
+}
+
+macro_rules! impl_point {
+    ($point:ident { $($field:ident),+ }, $tuple:ty) => {
+        impl<T: Component> $point<T> {
+            /// The sum of the distances along each axis.
+            #[must_use]
+            pub fn manhattan_distance(&self, other: &Self) -> T::Distance {
+                let distances = [$(self.$field.abs_diff(other.$field)),+];
+
+                distances.into_iter().reduce(|a, b| a + b).unwrap()
+            }
+
+            /// The largest of the distances along each axis, which is the number of king moves
+            /// between the points.
+            #[must_use]
+            pub fn chebyshev_distance(&self, other: &Self) -> T::Distance {
+                let distances = [$(self.$field.abs_diff(other.$field)),+];
+
+                distances.into_iter().max().unwrap()
+            }
+
+            /// The square of the straight-line distance, which stays an integer.
+            #[must_use]
+            pub fn squared_euclidean_distance(&self, other: &Self) -> T::Distance {
+                let distances = [$(self.$field.abs_diff(other.$field)),+];
+
+                distances.into_iter().map(|d| d * d).reduce(|a, b| a + b).unwrap()
+            }
+        }
+
+        impl<T> From<$tuple> for $point<T> {
+            fn from(($($field),+): $tuple) -> Self {
+                Self { $($field),+ }
+            }
+        }
+
+        impl<T> From<$point<T>> for $tuple {
+            fn from(point: $point<T>) -> Self {
+                ($(point.$field),+)
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$field += other.$field;)+
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                Self { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl<T: Copy + MulAssign> MulAssign<T> for $point<T> {
+            fn mul_assign(&mut self, factor: T) {
+                $(self.$field *= factor;)+
+            }
+        }
+    };
+}
+
+impl_point!(Point2 { x, y }, (T, T));
+impl_point!(Point3 { x, y, z }, (T, T, T));
+
+#[cfg(test)]
+mod tests {
+    use crate::Coordinate2D;
+
+    use super::*;
+
+    #[test]
+    fn test_point_3() {
+        let a = Point3::new(1_i32, -2, 3);
+        let mut b = Point3::from((2, 2, 2));
+
+        assert_eq!(a.manhattan_distance(&b), 6);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.squared_euclidean_distance(&b), 18);
+
+        b -= a;
+        b *= 2;
+        assert_eq!(b, Point3::new(2, 8, -2));
+        assert_eq!(b.to_grid_coord(), None);
+        assert_eq!(Point3::<u8>::from_grid_coord((1, 2, 300)), None);
+        assert!(Point3::new(0, 5, 5) < Point3::new(1, 0, 0));
+    }
+
+    #[test]
+    fn test_coordinate_2d() {
+        let a = Coordinate2D::new(3, -4);
+
+        assert_eq!(
+            a.translate(&Coordinate2D { x: -3, y: 4 }),
+            Coordinate2D::default()
+        );
+        assert_eq!(a.manhattan_distance(&Coordinate2D::new(0, 0)), 7_u64);
+        assert_eq!(
+            Coordinate2D::from_grid_coord((5, 6)),
+            Some(Coordinate2D::new(5, 6))
+        );
+    }
+}
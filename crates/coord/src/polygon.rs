@@ -0,0 +1,155 @@
+use crate::Point2;
+
+/// A simple polygon on the integer lattice, given by its vertices in order.
+///
+/// The last vertex is implicitly connected to the first one. Vertices may also lie on straight
+/// edges, e.g. every cell of a loop through a grid can be a vertex.
+///
+/// # Examples
+///
+/// ```
+/// use coord::{Point2, Polygon};
+///
+/// // A 4x3 rectangle
+/// let rectangle = Polygon::new(vec![
+///     Point2::new(0, 0),
+///     Point2::new(4, 0),
+///     Point2::new(4, 3),
+///     Point2::new(0, 3),
+/// ]);
+///
+/// assert_eq!(rectangle.area_doubled(), 24);
+/// assert_eq!(rectangle.boundary_points(), 14);
+/// assert_eq!(rectangle.interior_points(), 6);
+/// assert_eq!(rectangle.enclosed_points(), 20);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    vertices: Vec<Point2<i64>>,
+}
+
+impl Polygon {
+    #[must_use]
+    pub fn new(vertices: Vec<Point2<i64>>) -> Self {
+        Self { vertices }
+    }
+
+    /// Trace a polygon, starting at `start` and taking steps of `distance` units in a direction.
+    ///
+    /// Directions are anything that converts into a unit vector, e.g. a `direction::Direction`.
+    /// If the moves return to `start`, that last vertex is left out.
+    pub fn from_moves<D, I>(start: Point2<i64>, moves: I) -> Self
+    where
+        D: Into<Point2<i64>>,
+        I: IntoIterator<Item = (D, i64)>,
+    {
+        let mut vertices = vec![start];
+
+        for (direction, distance) in moves {
+            let last = *vertices.last().unwrap();
+            vertices.push(last + direction.into() * distance);
+        }
+
+        if vertices.len() > 1 && vertices.last() == Some(&start) {
+            vertices.pop();
+        }
+
+        Self { vertices }
+    }
+
+    #[must_use]
+    pub fn vertices(&self) -> &[Point2<i64>] {
+        &self.vertices
+    }
+
+    /// All edges as pairs of vertices, including the one closing the polygon.
+    pub fn edges(&self) -> impl Iterator<Item = (Point2<i64>, Point2<i64>)> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Twice the signed area, using the shoelace formula. Doubling keeps it an integer.
+    ///
+    /// The area is positive if the vertices go counter-clockwise with `y` growing upwards, which
+    /// is clockwise with `y` growing downwards, like rows of a grid.
+    #[must_use]
+    pub fn signed_area_doubled(&self) -> i64 {
+        self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+    }
+
+    /// Twice the area, using the shoelace formula.
+    #[must_use]
+    pub fn area_doubled(&self) -> u64 {
+        self.signed_area_doubled().unsigned_abs()
+    }
+
+    /// Number of lattice points on the edges.
+    #[must_use]
+    pub fn boundary_points(&self) -> u64 {
+        self.edges()
+            .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)))
+            .sum()
+    }
+
+    /// Number of lattice points strictly inside the polygon, using Pick's theorem.
+    #[must_use]
+    pub fn interior_points(&self) -> u64 {
+        match self.area_doubled() {
+            0 => 0,
+            area_doubled => (area_doubled + 2 - self.boundary_points()) / 2,
+        }
+    }
+
+    /// Number of lattice points inside or on the edges of the polygon.
+    #[must_use]
+    pub fn enclosed_points(&self) -> u64 {
+        self.interior_points() + self.boundary_points()
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polygon() {
+        // An L shape, traced clockwise with y growing downwards
+        let (right, down, left, up) = (
+            Point2::new(1, 0),
+            Point2::new(0, 1),
+            Point2::new(-1, 0),
+            Point2::new(0, -1),
+        );
+        let polygon = Polygon::from_moves(
+            Point2::new(0, 0),
+            [
+                (right, 4),
+                (down, 2),
+                (left, 2),
+                (down, 2),
+                (left, 2),
+                (up, 4),
+            ],
+        );
+
+        assert_eq!(polygon.vertices().len(), 6);
+        assert_eq!(polygon.signed_area_doubled(), 24);
+        assert_eq!(polygon.boundary_points(), 16);
+        assert_eq!(polygon.interior_points(), 5);
+        assert_eq!(polygon.enclosed_points(), 21);
+
+        let diagonal = Polygon::new(vec![
+            Point2::new(0, 0),
+            Point2::new(3, 3),
+            Point2::new(0, 3),
+        ]);
+        assert_eq!(diagonal.boundary_points(), 9);
+        assert_eq!(diagonal.interior_points(), 1);
+    }
+}
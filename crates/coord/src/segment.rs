@@ -0,0 +1,244 @@
+use crate::Point2;
+
+/// A line segment between two lattice points, including both ends.
+///
+/// Segments can go in any direction, but most puzzles only use axis-aligned ones, for which
+/// [`Segment::horizontal`] and [`Segment::vertical`] are shorthands.
+///
+/// # Examples
+///
+/// ```
+/// use coord::{Intersection, Point2, Segment};
+///
+/// let a = Segment::horizontal(0, 0, 8);
+/// let b = Segment::vertical(3, -2, 2);
+/// let c = Segment::horizontal(0, 6, 10);
+///
+/// assert_eq!(a.intersection(&b), Some(Intersection::Point(Point2::new(3, 0))));
+/// assert_eq!(
+///     a.intersection(&c),
+///     Some(Intersection::Segment(Segment::horizontal(0, 6, 8)))
+/// );
+/// assert_eq!(b.lattice_points().count(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub start: Point2<i64>,
+    pub end: Point2<i64>,
+}
+
+/// Where two segments meet, as returned by [`Segment::intersection`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Intersection {
+    Point(Point2<i64>),
+    /// The segments are collinear and share more than one point.
+    Segment(Segment),
+}
+
+fn cross(a: Point2<i64>, b: Point2<i64>) -> i64 {
+    a.x * b.y - a.y * b.x
+}
+
+fn dot(a: Point2<i64>, b: Point2<i64>) -> i64 {
+    a.x * b.x + a.y * b.y
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+impl Segment {
+    #[must_use]
+    pub const fn new(start: Point2<i64>, end: Point2<i64>) -> Self {
+        Self { start, end }
+    }
+
+    /// The segment on row `y` from column `x_start` to `x_end`.
+    #[must_use]
+    pub const fn horizontal(y: i64, x_start: i64, x_end: i64) -> Self {
+        Self::new(Point2::new(x_start, y), Point2::new(x_end, y))
+    }
+
+    /// The segment on column `x` from row `y_start` to `y_end`.
+    #[must_use]
+    pub const fn vertical(x: i64, y_start: i64, y_end: i64) -> Self {
+        Self::new(Point2::new(x, y_start), Point2::new(x, y_end))
+    }
+
+    #[must_use]
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    #[must_use]
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    #[must_use]
+    pub fn is_axis_aligned(&self) -> bool {
+        self.is_horizontal() || self.is_vertical()
+    }
+
+    /// The vector from `start` to `end`.
+    #[must_use]
+    pub fn delta(&self) -> Point2<i64> {
+        self.end - self.start
+    }
+
+    /// Number of lattice steps from `start` to `end`, which is the length of axis-aligned and
+    /// diagonal segments.
+    #[must_use]
+    pub fn steps(&self) -> u64 {
+        gcd(
+            self.start.x.abs_diff(self.end.x),
+            self.start.y.abs_diff(self.end.y),
+        )
+    }
+
+    /// All lattice points on the segment, from `start` to `end`.
+    pub fn lattice_points(&self) -> impl Iterator<Item = Point2<i64>> + use<> {
+        let steps = self.steps();
+        let start = self.start;
+        let step = if steps == 0 {
+            Point2::default()
+        } else {
+            let delta = self.delta();
+
+            Point2::new(delta.x / steps as i64, delta.y / steps as i64)
+        };
+
+        (0..=steps as i64).map(move |i| start + step * i)
+    }
+
+    /// Whether `point` lies on the segment.
+    #[must_use]
+    pub fn contains(&self, point: Point2<i64>) -> bool {
+        cross(self.delta(), point - self.start) == 0
+            && (self.start.x.min(self.end.x)..=self.start.x.max(self.end.x)).contains(&point.x)
+            && (self.start.y.min(self.end.y)..=self.start.y.max(self.end.y)).contains(&point.y)
+    }
+
+    /// Whether all of `other` lies on this segment.
+    #[must_use]
+    pub fn contains_segment(&self, other: &Self) -> bool {
+        self.contains(other.start) && self.contains(other.end)
+    }
+
+    /// The part two collinear segments have in common, which is pointing in the direction of
+    /// `self`. Returns `None` if the segments aren't collinear or don't touch.
+    #[must_use]
+    pub fn overlap(&self, other: &Self) -> Option<Self> {
+        let delta = self.delta();
+
+        if delta == Point2::default() {
+            return other.contains(self.start).then_some(*self);
+        }
+        if cross(delta, other.start - self.start) != 0 || cross(delta, other.end - self.start) != 0
+        {
+            return None;
+        }
+
+        // Order all points by how far along `self` they are
+        let along = |point: Point2<i64>| dot(point - self.start, delta);
+        let (other_first, other_last) = if along(other.start) <= along(other.end) {
+            (other.start, other.end)
+        } else {
+            (other.end, other.start)
+        };
+        let start = if along(other_first) > 0 {
+            other_first
+        } else {
+            self.start
+        };
+        let end = if along(other_last) < along(self.end) {
+            other_last
+        } else {
+            self.end
+        };
+
+        (along(start) <= along(end)).then_some(Self::new(start, end))
+    }
+
+    /// Where this segment meets `other`.
+    ///
+    /// Only lattice points count, so segments crossing between lattice points (which only
+    /// non-axis-aligned segments can do) don't intersect.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Intersection> {
+        let (d1, d2) = (self.delta(), other.delta());
+        let denominator = cross(d1, d2);
+
+        if denominator == 0 {
+            if d2 == Point2::default() {
+                return self
+                    .contains(other.start)
+                    .then_some(Intersection::Point(other.start));
+            }
+
+            return self.overlap(other).map(|overlap| {
+                if overlap.start == overlap.end {
+                    Intersection::Point(overlap.start)
+                } else {
+                    Intersection::Segment(overlap)
+                }
+            });
+        }
+
+        // Solve `self.start + t * d1 == other.start + u * d2` for `t` and `u` in `0..=1`
+        let offset = other.start - self.start;
+        let (mut t, mut u, mut denominator) = (cross(offset, d2), cross(offset, d1), denominator);
+        if denominator < 0 {
+            (t, u, denominator) = (-t, -u, -denominator);
+        }
+        if !(0..=denominator).contains(&t) || !(0..=denominator).contains(&u) {
+            return None;
+        }
+
+        let (x, y) = (d1.x * t, d1.y * t);
+        (x % denominator == 0 && y % denominator == 0).then(|| {
+            Intersection::Point(self.start + Point2::new(x / denominator, y / denominator))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        let diagonal = Segment::new(Point2::new(0, 0), Point2::new(6, 4));
+        assert_eq!(
+            diagonal.lattice_points().collect::<Vec<_>>(),
+            vec![Point2::new(0, 0), Point2::new(3, 2), Point2::new(6, 4)]
+        );
+        assert!(diagonal.contains(Point2::new(3, 2)));
+        assert!(!diagonal.contains(Point2::new(9, 6)));
+        assert!(!diagonal.is_axis_aligned());
+
+        // Crossing between lattice points
+        let crossing = Segment::new(Point2::new(0, 1), Point2::new(1, 0));
+        assert_eq!(
+            Segment::new(Point2::new(0, 0), Point2::new(1, 1)).intersection(&crossing),
+            None
+        );
+        assert_eq!(
+            diagonal.intersection(&Segment::vertical(3, 5, -5)),
+            Some(Intersection::Point(Point2::new(3, 2)))
+        );
+
+        // Collinear, touching and reversed segments
+        let reversed = Segment::horizontal(1, 9, 4);
+        assert_eq!(
+            Segment::horizontal(1, 0, 6).overlap(&reversed),
+            Some(Segment::horizontal(1, 4, 6))
+        );
+        assert_eq!(
+            Segment::horizontal(1, 0, 4).intersection(&reversed),
+            Some(Intersection::Point(Point2::new(4, 1)))
+        );
+        assert_eq!(Segment::horizontal(1, 0, 3).intersection(&reversed), None);
+        assert!(reversed.contains_segment(&Segment::horizontal(1, 5, 8)));
+    }
+}
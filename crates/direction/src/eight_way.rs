@@ -0,0 +1,174 @@
+use aoc_macros::EnumVariants;
+use aoc_utils::EnumVariants;
+use coord::Point2;
+
+use crate::Direction;
+
+/// One of the four orthogonal or four diagonal directions, like a compass rose with `Up` as
+/// north.
+///
+/// Variants are listed clockwise, starting at `Up`. Steps follow the same conventions as
+/// [`Direction`].
+///
+/// # Examples
+///
+/// ```
+/// use aoc_utils::EnumVariants;
+/// use direction::{Direction, Direction8};
+///
+/// assert_eq!(Direction8::UpRight.turn_right(), Direction8::DownRight);
+/// assert_eq!(Direction8::UpRight.opposite(), Direction8::DownLeft);
+/// assert_eq!(Direction8::from(Direction::Left).turn_right_45(), Direction8::UpLeft);
+/// assert_eq!(Direction::try_from(Direction8::Down), Ok(Direction::Down));
+/// assert_eq!(
+///     Direction8::variants().iter().filter(|d| d.is_diagonal()).count(),
+///     4
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumVariants)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Rotate clockwise by `steps` eighths of a turn.
+    fn rotate(self, steps: usize) -> Self {
+        Self::variants()[(self as usize + steps) % 8]
+    }
+
+    #[must_use]
+    pub fn is_diagonal(&self) -> bool {
+        *self as usize % 2 == 1
+    }
+
+    /// Rotate by 45° clockwise.
+    #[must_use]
+    pub fn turn_right_45(&self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Rotate by 45° counter-clockwise.
+    #[must_use]
+    pub fn turn_left_45(&self) -> Self {
+        self.rotate(7)
+    }
+
+    /// Rotate by 90° clockwise.
+    #[must_use]
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Rotate by 90° counter-clockwise.
+    #[must_use]
+    pub fn turn_left(&self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Rotate by 180°.
+    #[must_use]
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    /// The `(dx, dy)` of a single step in this direction.
+    #[must_use]
+    pub const fn delta(&self) -> (isize, isize) {
+        match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+            Direction::Right => Direction8::Right,
+        }
+    }
+}
+
+/// Convert an orthogonal direction; diagonal directions are returned as the error.
+impl TryFrom<Direction8> for Direction {
+    type Error = Direction8;
+
+    fn try_from(direction: Direction8) -> Result<Self, Self::Error> {
+        match direction {
+            Direction8::Up => Ok(Direction::Up),
+            Direction8::Down => Ok(Direction::Down),
+            Direction8::Left => Ok(Direction::Left),
+            Direction8::Right => Ok(Direction::Right),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+/// The unit vector of a direction, diagonals being one step along both axes.
+impl From<Direction8> for Point2<i64> {
+    fn from(direction: Direction8) -> Self {
+        let (dx, dy) = direction.delta();
+
+        Point2::new(dx as i64, dy as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate() {
+        let clockwise = Direction8::variants();
+
+        for (index, direction) in clockwise.iter().enumerate() {
+            for steps in 0..=8 {
+                assert_eq!(direction.rotate(steps), clockwise[(index + steps) % 8]);
+            }
+
+            assert_eq!(direction.turn_right_45(), direction.rotate(1));
+            assert_eq!(direction.turn_right(), direction.rotate(2));
+            assert_eq!(direction.opposite(), direction.rotate(4));
+            assert_eq!(direction.turn_left(), direction.rotate(6));
+            assert_eq!(direction.turn_left_45(), direction.rotate(7));
+            assert_eq!(direction.turn_left_45().turn_right_45(), *direction);
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        for direction in Direction::variants() {
+            let direction8 = Direction8::from(direction);
+
+            assert!(!direction8.is_diagonal());
+            assert_eq!(Direction::try_from(direction8), Ok(direction));
+            assert_eq!(
+                Point2::<i64>::from(direction8),
+                Point2::<i64>::from(direction)
+            );
+        }
+
+        for diagonal in Direction8::variants()
+            .into_iter()
+            .filter(Direction8::is_diagonal)
+        {
+            assert_eq!(Direction::try_from(diagonal), Err(diagonal));
+        }
+    }
+}
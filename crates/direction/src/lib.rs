@@ -1,11 +1,26 @@
 use aoc_utils::EnumVariants;
+use coord::Point2;
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Write;
 use std::iter::successors;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 use aoc_macros::EnumVariants;
 
+mod eight_way;
+mod parse;
+mod turtle;
+
+pub use eight_way::Direction8;
+pub use parse::ParseDirectionError;
+pub use turtle::{Command, ParseCommandError, Turtle};
+
+/// One of the four orthogonal directions on a grid.
+///
+/// `y` grows downwards like rows of a grid, so `Up` decreases `y`. Like other arithmetic,
+/// stepping below zero with unsigned coordinates panics in debug builds; use
+/// [`Direction::advance`] to check for that.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, EnumVariants)]
 pub enum Direction {
     Up,
@@ -26,8 +41,6 @@ impl Debug for Direction {
     }
 }
 
-type Coord = (usize, usize);
-
 impl Direction {
     /// Determine the direction to go from `from` to `to`.
     ///
@@ -35,9 +48,9 @@ impl Direction {
     ///   - `from == to`
     ///   - `from` and `to` aren't on either the same horizontal or vertical plane (`from.x != to.x
     ///   && from.y != to.y`)
-    #[must_use] pub fn determine(from: &Coord, to: &Coord) -> Option<Self> {
+    #[must_use] pub fn determine(from: &Point2<usize>, to: &Point2<usize>) -> Option<Self> {
         use std::cmp::Ordering::{Greater, Equal, Less};
-        match (to.0.cmp(&from.0), to.1.cmp(&from.1)) {
+        match (to.x.cmp(&from.x), to.y.cmp(&from.y)) {
             (Greater, Equal) => Self::Right.into(),
             (Less, Equal) => Self::Left.into(),
             (Equal, Greater) => Self::Down.into(),
@@ -64,20 +77,41 @@ impl Direction {
         }
     }
 
-    #[must_use] pub fn advance(&self, coord: &Coord, steps: usize) -> Option<Coord> {
+    #[must_use]
+    pub fn opposite(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// The `(dx, dy)` of a single step in this direction.
+    #[must_use]
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    #[must_use] pub fn advance(&self, coord: &Point2<usize>, steps: usize) -> Option<Point2<usize>> {
         match self {
-            Direction::Up => coord.1.checked_sub(steps).map(|y| (coord.0, y)),
-            Direction::Down => Some((coord.0, coord.1 + steps)),
-            Direction::Left => coord.0.checked_sub(steps).map(|x| (x, coord.1)),
-            Direction::Right => Some((coord.0 + steps, coord.1)),
+            Direction::Up => coord.y.checked_sub(steps).map(|y| Point2::new(coord.x, y)),
+            Direction::Down => Some(Point2::new(coord.x, coord.y + steps)),
+            Direction::Left => coord.x.checked_sub(steps).map(|x| Point2::new(x, coord.y)),
+            Direction::Right => Some(Point2::new(coord.x + steps, coord.y)),
         }
     }
 
     #[must_use] pub fn advance_with_intermediate_coords(
         &self,
-        coord: &Coord,
+        coord: &Point2<usize>,
         steps: usize,
-    ) -> Option<Vec<Coord>> {
+    ) -> Option<Vec<Point2<usize>>> {
         let result = successors(self.advance(coord, 1), |next| self.advance(next, 1))
             .take(steps)
             .collect::<Vec<_>>();
@@ -100,3 +134,77 @@ impl From<Direction> for char {
         }
     }
 }
+
+/// The unit vector of a direction.
+impl From<Direction> for Point2<i64> {
+    fn from(direction: Direction) -> Self {
+        let (dx, dy) = direction.delta();
+
+        Point2::new(dx as i64, dy as i64)
+    }
+}
+
+/// Take a single step in a direction.
+///
+/// # Examples
+///
+/// ```
+/// use coord::Point2;
+/// use direction::Direction;
+///
+/// let mut position = Point2::new(3_usize, 3);
+/// position += Direction::Up;
+///
+/// assert_eq!(position, Point2::new(3, 2));
+/// assert_eq!(position + Direction::Left - Direction::Down, Point2::new(2, 1));
+/// ```
+impl<T> Add<Direction> for Point2<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Point2::new(self.x, self.y - T::from(1)),
+            Direction::Down => Point2::new(self.x, self.y + T::from(1)),
+            Direction::Left => Point2::new(self.x - T::from(1), self.y),
+            Direction::Right => Point2::new(self.x + T::from(1), self.y),
+        }
+    }
+}
+
+/// Take a single step in the opposite of a direction.
+impl<T> Sub<Direction> for Point2<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    type Output = Self;
+
+    fn sub(self, direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Point2::new(self.x, self.y + T::from(1)),
+            Direction::Down => Point2::new(self.x, self.y - T::from(1)),
+            Direction::Left => Point2::new(self.x + T::from(1), self.y),
+            Direction::Right => Point2::new(self.x - T::from(1), self.y),
+        }
+    }
+}
+
+impl<T> AddAssign<Direction> for Point2<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl<T> SubAssign<Direction> for Point2<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    fn sub_assign(&mut self, direction: Direction) {
+        *self = *self - direction;
+    }
+}
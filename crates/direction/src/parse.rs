@@ -0,0 +1,161 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::Direction;
+
+/// Error returned when a character or string isn't a known direction.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseDirectionError {
+    UnknownChar(char),
+    /// The string isn't a single character.
+    InvalidLength(String),
+}
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDirectionError::UnknownChar(c) => write!(f, "Unknown direction: {c:?}"),
+            ParseDirectionError::InvalidLength(s) => {
+                write!(f, "Expected a single character direction, got {s:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+/// Parse a direction from any of the common notations:
+///
+/// | Notation  | Up       | Down     | Left     | Right    |
+/// |-----------|----------|----------|----------|----------|
+/// | Arrows    | `^`      | `v`      | `<`      | `>`      |
+/// | Letters   | `U`, `u` | `D`, `d` | `L`, `l` | `R`, `r` |
+/// | Compass   | `N`, `n` | `S`, `s` | `W`, `w` | `E`, `e` |
+/// | Numeric   | `3`      | `1`      | `2`      | `0`      |
+///
+/// The numeric notation counts quarter turns clockwise, starting at `Right`.
+///
+/// # Examples
+///
+/// ```
+/// use direction::{Direction, ParseDirectionError};
+///
+/// assert_eq!(Direction::try_from('W'), Ok(Direction::Left));
+/// assert_eq!("3".parse(), Ok(Direction::Up));
+/// assert_eq!(Direction::try_from('x'), Err(ParseDirectionError::UnknownChar('x')));
+/// assert!("UD".parse::<Direction>().is_err());
+/// ```
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' | 'u' | 'N' | 'n' | '3' => Ok(Direction::Up),
+            'v' | 'D' | 'd' | 'S' | 's' | '1' => Ok(Direction::Down),
+            '<' | 'L' | 'l' | 'W' | 'w' | '2' => Ok(Direction::Left),
+            '>' | 'R' | 'r' | 'E' | 'e' | '0' => Ok(Direction::Right),
+            c => Err(ParseDirectionError::UnknownChar(c)),
+        }
+    }
+}
+
+/// Parse a single character in any of the notations supported by
+/// [`TryFrom<char>`](#impl-TryFrom<char>-for-Direction).
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(ParseDirectionError::InvalidLength(s.to_string())),
+        }
+    }
+}
+
+impl Direction {
+    /// Parse a direction per character, skipping whitespace, e.g. a list of moves spread over
+    /// several lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the first character that isn't a direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use direction::Direction;
+    ///
+    /// assert_eq!(
+    ///     Direction::parse_moves("<^\nv>"),
+    ///     Ok(vec![Direction::Left, Direction::Up, Direction::Down, Direction::Right])
+    /// );
+    /// assert!(Direction::parse_moves("<^x").is_err());
+    /// ```
+    pub fn parse_moves(s: &str) -> Result<Vec<Direction>, ParseDirectionError> {
+        s.chars()
+            .filter(|c| !c.is_whitespace())
+            .map(Direction::try_from)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_char() {
+        for (chars, direction) in [
+            ("^UuNn3", Direction::Up),
+            ("vDdSs1", Direction::Down),
+            ("<LlWw2", Direction::Left),
+            (">RrEe0", Direction::Right),
+        ] {
+            for c in chars.chars() {
+                assert_eq!(Direction::try_from(c), Ok(direction));
+                assert_eq!(c.to_string().parse(), Ok(direction));
+            }
+        }
+
+        assert_eq!(
+            Direction::try_from('x'),
+            Err(ParseDirectionError::UnknownChar('x'))
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "".parse::<Direction>(),
+            Err(ParseDirectionError::InvalidLength(String::new()))
+        );
+        assert_eq!(
+            "^>".parse::<Direction>(),
+            Err(ParseDirectionError::InvalidLength("^>".to_string()))
+        );
+        assert_eq!(
+            "?".parse::<Direction>(),
+            Err(ParseDirectionError::UnknownChar('?'))
+        );
+    }
+
+    #[test]
+    fn test_parse_moves() {
+        assert_eq!(
+            Direction::parse_moves(" U\tr\r\nd  L\n"),
+            Ok(vec![
+                Direction::Up,
+                Direction::Right,
+                Direction::Down,
+                Direction::Left
+            ])
+        );
+        assert_eq!(Direction::parse_moves(" \n"), Ok(vec![]));
+        assert_eq!(
+            Direction::parse_moves("^>\nv?<"),
+            Err(ParseDirectionError::UnknownChar('?'))
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt::Display;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+use coord::Point2;
+
+use crate::Direction;
+
+/// A position with a heading, moved around by turning and walking, like a robot or guard.
+///
+/// Turtles are hashable, so a set of visited turtles tells when a walk starts going in circles.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use coord::Point2;
+/// use direction::{Command, Direction, Turtle};
+///
+/// let mut turtle = Turtle::new(Point2::new(0_i64, 0), Direction::Up);
+/// let mut visited = HashSet::new();
+///
+/// for command in Command::parse_list("R,8,L,10,L,5").unwrap() {
+///     turtle = turtle.apply(command);
+/// }
+/// assert_eq!(turtle.position, Point2::new(3, -10));
+/// assert_eq!(turtle.heading, Direction::Left);
+///
+/// // Walking around a square ends up where it started
+/// while visited.insert(turtle) {
+///     turtle = turtle.forward(2).turn_left();
+/// }
+/// assert_eq!(visited.len(), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Turtle<T> {
+    pub position: Point2<T>,
+    pub heading: Direction,
+}
+
+impl<T> Turtle<T> {
+    #[must_use]
+    pub const fn new(position: Point2<T>, heading: Direction) -> Self {
+        Self { position, heading }
+    }
+
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self {
+            heading: self.heading.turn_left(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self {
+            heading: self.heading.turn_right(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn turn_around(self) -> Self {
+        Self {
+            heading: self.heading.opposite(),
+            ..self
+        }
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T>> Turtle<T> {
+    /// Walk `steps` units in the direction of the heading.
+    #[must_use]
+    pub fn forward(self, steps: T) -> Self {
+        let Point2 { x, y } = self.position;
+        let position = match self.heading {
+            Direction::Up => Point2::new(x, y - steps),
+            Direction::Down => Point2::new(x, y + steps),
+            Direction::Left => Point2::new(x - steps, y),
+            Direction::Right => Point2::new(x + steps, y),
+        };
+
+        Self { position, ..self }
+    }
+
+    /// Walk `steps` units backwards, keeping the heading.
+    #[must_use]
+    pub fn back(self, steps: T) -> Self {
+        self.turn_around().forward(steps).turn_around()
+    }
+
+    #[must_use]
+    pub fn apply(self, command: Command<T>) -> Self {
+        match command {
+            Command::TurnLeft => self.turn_left(),
+            Command::TurnRight => self.turn_right(),
+            Command::Forward(steps) => self.forward(steps),
+        }
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + From<u8>> Turtle<T> {
+    /// The position one step ahead.
+    #[must_use]
+    pub fn ahead(&self) -> Point2<T> {
+        self.position + self.heading
+    }
+}
+
+/// A movement command for a [`Turtle`], written as `L`, `R` or a number of steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Command<T> {
+    TurnLeft,
+    TurnRight,
+    Forward(T),
+}
+
+/// Error returned when a movement command is neither a turn nor a number of steps.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseCommandError(pub String);
+
+impl Display for ParseCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown movement command: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseCommandError {}
+
+impl<T: FromStr> FromStr for Command<T> {
+    type Err = ParseCommandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "L" => Ok(Command::TurnLeft),
+            "R" => Ok(Command::TurnRight),
+            steps => steps
+                .parse()
+                .map(Command::Forward)
+                .map_err(|_| ParseCommandError(s.to_string())),
+        }
+    }
+}
+
+impl<T: FromStr> Command<T> {
+    /// Parse comma separated commands, like `R,8,L,10`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the first command that can't be parsed.
+    pub fn parse_list(s: &str) -> Result<Vec<Self>, ParseCommandError> {
+        s.split(',').map(str::parse).collect()
+    }
+}
+
+impl<T: Display> Display for Command<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Command::TurnLeft => write!(f, "L"),
+            Command::TurnRight => write!(f, "R"),
+            Command::Forward(steps) => write!(f, "{steps}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moves() {
+        let turtle = Turtle::new(Point2::new(2_i64, 3), Direction::Right);
+
+        assert_eq!(turtle.ahead(), Point2::new(3, 3));
+        assert_eq!(turtle.forward(1).position, turtle.ahead());
+        assert_eq!(
+            turtle.back(5),
+            Turtle::new(Point2::new(-3, 3), Direction::Right)
+        );
+        assert_eq!(turtle.back(5).forward(5), turtle);
+
+        let turned = turtle.turn_around();
+        assert_eq!(turned.heading, Direction::Left);
+        assert_eq!(turned.position, turtle.position);
+        assert_eq!(turned.ahead(), Point2::new(1, 3));
+        assert_eq!(turned.turn_around(), turtle);
+        assert_eq!(turtle.turn_left().turn_left(), turned);
+
+        // Walking back with unsigned coordinates, while heading up
+        let turtle = Turtle::new(Point2::new(4_usize, 0), Direction::Up);
+        assert_eq!(turtle.back(2).position, Point2::new(4, 2));
+        assert_eq!(turtle.turn_right().ahead(), Point2::new(5, 0));
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            Command::parse_list("R, 8,L,10"),
+            Ok(vec![
+                Command::TurnRight,
+                Command::Forward(8_u32),
+                Command::TurnLeft,
+                Command::Forward(10),
+            ])
+        );
+        assert_eq!(
+            Command::<u32>::parse_list("R,,8"),
+            Err(ParseCommandError(String::new()))
+        );
+        assert_eq!(
+            Command::<u32>::parse_list(""),
+            Err(ParseCommandError(String::new()))
+        );
+        assert_eq!(
+            Command::<u32>::parse_list("R,-3"),
+            Err(ParseCommandError("-3".to_string()))
+        );
+        assert_eq!(
+            Command::<u32>::parse_list("L,x"),
+            Err(ParseCommandError("x".to_string()))
+        );
+        assert_eq!(Command::TurnLeft::<u32>.to_string(), "L");
+        assert_eq!(Command::Forward(12).to_string(), "12");
+    }
+}
@@ -0,0 +1,349 @@
+//! A terminal viewer for watching simulations on a [`Grid`] play out.
+//!
+//! Every call to [`Viewer::show`] draws a frame and then waits for the current delay, while
+//! handling these keys:
+//!
+//! | Key                | Action                                          |
+//! |--------------------|-------------------------------------------------|
+//! | `space`            | Pause or resume                                 |
+//! | `n` / `.`          | Show the next frame (while paused)              |
+//! | `+` / `-`          | Halve or double the delay between frames        |
+//! | arrows / `hjkl`    | Scroll                                          |
+//! | `z` / `x`          | Zoom in or out                                  |
+//! | `0`                | Reset scrolling and zoom                        |
+//! | `q` / `esc`        | Stop viewing; the simulation carries on without |
+//!
+//! The initial delay is taken from the `DELAY_MILLIS` environment variable, if set.
+
+use std::time::{Duration, Instant};
+
+use grid::{Grid, SparseGrid};
+use ratatui::DefaultTerminal;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::Alignment;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, BorderType, Paragraph};
+
+pub use ratatui::style::Color;
+
+/// What the simulation should do after showing a frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Control {
+    Continue,
+    /// The viewer was closed; further frames are ignored.
+    Quit,
+}
+
+/// Which part of a grid is shown, and at which zoom level.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Viewport {
+    /// Top-left cell shown.
+    offset: (usize, usize),
+    /// Zoomed in by `zoom + 1` characters per cell if positive, zoomed out by showing every
+    /// `1 - zoom`th cell if negative.
+    zoom: isize,
+}
+
+impl Viewport {
+    fn factor(&self) -> usize {
+        self.zoom.unsigned_abs() + 1
+    }
+
+    /// The cell shown at `(x, y)` of the screen.
+    fn source_coord(&self, (x, y): (usize, usize)) -> (usize, usize) {
+        let factor = self.factor();
+
+        if self.zoom >= 0 {
+            (self.offset.0 + x / factor, self.offset.1 + y / factor)
+        } else {
+            (self.offset.0 + x * factor, self.offset.1 + y * factor)
+        }
+    }
+
+    /// Scroll by `(dx, dy)` screen characters, staying inside a `width`x`height` grid.
+    fn scroll(&mut self, (dx, dy): (isize, isize), width: usize, height: usize) {
+        let cells = |d: isize| {
+            if self.zoom >= 0 {
+                d
+            } else {
+                d * self.factor() as isize
+            }
+        };
+
+        self.offset = (
+            self.offset
+                .0
+                .saturating_add_signed(cells(dx))
+                .min(width.saturating_sub(1)),
+            self.offset
+                .1
+                .saturating_add_signed(cells(dy))
+                .min(height.saturating_sub(1)),
+        );
+    }
+}
+
+/// A full-screen terminal viewer; the terminal is restored when it's dropped.
+pub struct Viewer {
+    terminal: Option<DefaultTerminal>,
+    viewport: Viewport,
+    delay: Duration,
+    paused: bool,
+    title: String,
+    start_time: Instant,
+    last_grid_size: (usize, usize),
+    /// Coordinates of the top-left cell, when showing a [`SparseGrid`].
+    origin: Option<(i64, i64)>,
+}
+
+impl Viewer {
+    /// Take over the terminal.
+    #[must_use]
+    pub fn init() -> Self {
+        let delay = std::env::var("DELAY_MILLIS")
+            .map(|delay| delay.parse().expect("Invalid DELAY_MILLIS value"))
+            .map_or(Duration::ZERO, Duration::from_millis);
+
+        Self {
+            terminal: Some(ratatui::init()),
+            viewport: Viewport::default(),
+            delay,
+            paused: false,
+            title: String::new(),
+            start_time: Instant::now(),
+            last_grid_size: (0, 0),
+            origin: None,
+        }
+    }
+
+    /// Text shown above the grid, e.g. a score.
+    pub fn set_title<S: Into<String>>(&mut self, title: S) {
+        self.title = title.into();
+    }
+
+    /// Draw `grid`, converting cells into characters and colours using `cell`, then wait for the
+    /// current delay (or, while paused, until the user steps or resumes).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the terminal can't be drawn to or read from.
+    pub fn show<T, F: Fn(&T) -> (char, Color)>(&mut self, grid: &Grid<T>, cell: F) -> Control {
+        self.origin = None;
+
+        self.show_frame(grid, cell)
+    }
+
+    /// Like [`Viewer::show`], for a [`SparseGrid`]. Its top-left cell is drawn in the top-left
+    /// corner of the screen; the status line shows the sparse coordinates of the top-left cell
+    /// on screen.
+    pub fn show_sparse<T: Clone, F: Fn(&T) -> (char, Color)>(
+        &mut self,
+        grid: &SparseGrid<T>,
+        cell: F,
+    ) -> Control {
+        let (grid, origin) = grid.to_grid();
+        self.origin = Some(origin);
+
+        self.show_frame(&grid, cell)
+    }
+
+    fn show_frame<T, F: Fn(&T) -> (char, Color)>(&mut self, grid: &Grid<T>, cell: F) -> Control {
+        if self.terminal.is_none() {
+            return Control::Quit;
+        }
+
+        self.draw(grid, &cell, None);
+
+        let deadline = Instant::now() + self.delay;
+
+        loop {
+            let timeout = if self.paused {
+                None
+            } else {
+                Some(deadline.saturating_duration_since(Instant::now()))
+            };
+
+            match self.next_key(timeout) {
+                None => return Control::Continue,
+                Some(KeyCode::Char('q') | KeyCode::Esc) => {
+                    self.close();
+
+                    return Control::Quit;
+                }
+                Some(KeyCode::Char('n' | '.')) if self.paused => return Control::Continue,
+                Some(key) => {
+                    self.handle_key(key);
+                    self.draw(grid, &cell, None);
+                }
+            }
+        }
+    }
+
+    /// Draw `grid` a last time with `message` below it, and wait until `q`, `esc` or `enter` is
+    /// pressed, or `timeout` has passed. The grid can still be scrolled and zoomed meanwhile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the terminal can't be drawn to or read from.
+    pub fn finish<T, F: Fn(&T) -> (char, Color)>(
+        mut self,
+        grid: &Grid<T>,
+        cell: F,
+        message: &str,
+        timeout: Duration,
+    ) {
+        if self.terminal.is_none() {
+            return;
+        }
+
+        self.origin = None;
+        self.draw(grid, &cell, Some(message));
+
+        let deadline = Instant::now() + timeout;
+
+        while let Some(key) =
+            self.next_key(Some(deadline.saturating_duration_since(Instant::now())))
+        {
+            match key {
+                KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter => break,
+                key => {
+                    self.handle_key(key);
+                    self.draw(grid, &cell, Some(message));
+                }
+            }
+        }
+    }
+
+    /// Wait for a key press, or until `timeout` runs out.
+    fn next_key(&self, timeout: Option<Duration>) -> Option<KeyCode> {
+        loop {
+            if let Some(timeout) = timeout
+                && !event::poll(timeout).expect("Couldn't poll terminal events")
+            {
+                return None;
+            }
+
+            if let Event::Key(key) = event::read().expect("Couldn't read terminal events")
+                && key.kind == KeyEventKind::Press
+            {
+                return Some(key.code);
+            }
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyCode) {
+        let (width, height) = self.last_grid_size;
+
+        match key {
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::Char('+') => self.delay /= 2,
+            KeyCode::Char('-') => self.delay = (self.delay * 2).max(Duration::from_millis(1)),
+            KeyCode::Left | KeyCode::Char('h') => self.viewport.scroll((-4, 0), width, height),
+            KeyCode::Right | KeyCode::Char('l') => self.viewport.scroll((4, 0), width, height),
+            KeyCode::Up | KeyCode::Char('k') => self.viewport.scroll((0, -2), width, height),
+            KeyCode::Down | KeyCode::Char('j') => self.viewport.scroll((0, 2), width, height),
+            KeyCode::Char('z') => self.viewport.zoom += 1,
+            KeyCode::Char('x') => self.viewport.zoom -= 1,
+            KeyCode::Char('0') => self.viewport = Viewport::default(),
+            _ => {}
+        }
+    }
+
+    fn draw<T, F: Fn(&T) -> (char, Color)>(
+        &mut self,
+        grid: &Grid<T>,
+        cell: &F,
+        message: Option<&str>,
+    ) {
+        self.last_grid_size = (grid.width(), grid.height());
+
+        let viewport = self.viewport;
+        let elapsed = self.start_time.elapsed().as_secs();
+        let position = self.origin.map_or(String::new(), |(x, y)| {
+            format!(
+                " | top-left ({}, {})",
+                x + viewport.offset.0 as i64,
+                y + viewport.offset.1 as i64
+            )
+        });
+        let status = match message {
+            Some(message) => format!(" [ {message} ] "),
+            None => format!(
+                " [ {:02}:{:02} | {} ms{}{position} ] ",
+                elapsed / 60,
+                elapsed % 60,
+                self.delay.as_millis(),
+                if self.paused { " | paused" } else { "" }
+            ),
+        };
+        let title = if self.title.is_empty() {
+            String::new()
+        } else {
+            format!(" [ {} ] ", self.title)
+        };
+
+        let Some(terminal) = &mut self.terminal else {
+            return;
+        };
+
+        terminal
+            .draw(|frame| {
+                let block = Block::bordered()
+                    .border_type(BorderType::Rounded)
+                    .title(title)
+                    .title_bottom(status)
+                    .title_alignment(Alignment::Center);
+                let area = block.inner(frame.area());
+                let lines = (0..usize::from(area.height))
+                    .map(|y| {
+                        (0..usize::from(area.width))
+                            .filter_map(|x| {
+                                let (column, row) = viewport.source_coord((x, y));
+                                let (c, color) = cell(grid.get(column, row)?);
+
+                                Some(Span::styled(c.to_string(), Style::default().fg(color)))
+                            })
+                            .collect::<Line>()
+                    })
+                    .collect::<Vec<_>>();
+
+                frame.render_widget(Paragraph::new(Text::from(lines)).block(block), frame.area());
+            })
+            .expect("Couldn't draw to the terminal");
+    }
+
+    fn close(&mut self) {
+        if self.terminal.take().is_some() {
+            ratatui::restore();
+        }
+    }
+}
+
+impl Drop for Viewer {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_viewport() {
+        let mut viewport = Viewport::default();
+        assert_eq!(viewport.source_coord((3, 2)), (3, 2));
+
+        viewport.zoom = 1;
+        assert_eq!(viewport.source_coord((3, 2)), (1, 1));
+
+        viewport.zoom = -2;
+        assert_eq!(viewport.source_coord((3, 2)), (9, 6));
+
+        viewport.scroll((2, -1), 10, 10);
+        assert_eq!(viewport.offset, (6, 0));
+        viewport.scroll((2, 5), 10, 10);
+        assert_eq!(viewport.offset, (9, 9));
+    }
+}
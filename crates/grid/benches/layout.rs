@@ -0,0 +1,207 @@
+//! Compares [`Grid`]'s flat storage with the nested `Vec<Vec<T>>` layout it used to have, on
+//! workloads resembling 2023/14 (tilting a platform) and 2024/06 (walking a guard around a lab).
+//!
+//! The puzzles' examples are tiled to roughly the size of the real inputs.
+
+use divan::{Bencher, black_box};
+use grid::Grid;
+
+fn main() {
+    divan::main();
+}
+
+const PLATFORM: &str = include_str!("../../../years/2023/14/src/test-input");
+const LAB: &str = include_str!("../../../years/2024/06/src/test-input");
+
+/// The grid operations the workloads below use.
+trait Layout<T> {
+    /// The same layout, holding cells of another type.
+    type With<U>: Layout<U>;
+
+    fn from_rows(rows: Vec<Vec<T>>) -> Self;
+    fn with_size(width: usize, height: usize) -> Self
+    where
+        T: Default;
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    fn get(&self, column: usize, row: usize) -> Option<&T>;
+    fn update(&mut self, column: usize, row: usize, value: T);
+}
+
+impl<T> Layout<T> for Grid<T> {
+    type With<U> = Grid<U>;
+
+    fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        Grid::new(rows).unwrap()
+    }
+
+    fn with_size(width: usize, height: usize) -> Self
+    where
+        T: Default,
+    {
+        Grid::with_size(width, height)
+    }
+
+    fn width(&self) -> usize {
+        self.width()
+    }
+
+    fn height(&self) -> usize {
+        self.height()
+    }
+
+    fn get(&self, column: usize, row: usize) -> Option<&T> {
+        self.get(column, row)
+    }
+
+    fn update(&mut self, column: usize, row: usize, value: T) {
+        self.update(column, row, value);
+    }
+}
+
+/// The layout [`Grid`] had before it switched to a single `Vec`.
+struct NestedGrid<T> {
+    width: usize,
+    rows: Vec<Vec<T>>,
+}
+
+impl<T> Layout<T> for NestedGrid<T> {
+    type With<U> = NestedGrid<U>;
+
+    fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        NestedGrid {
+            width: rows.first().map_or(0, Vec::len),
+            rows,
+        }
+    }
+
+    fn with_size(width: usize, height: usize) -> Self
+    where
+        T: Default,
+    {
+        NestedGrid {
+            width,
+            rows: (0..height)
+                .map(|_| (0..width).map(|_| T::default()).collect())
+                .collect(),
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn get(&self, column: usize, row: usize) -> Option<&T> {
+        self.rows.get(row).and_then(|row| row.get(column))
+    }
+
+    fn update(&mut self, column: usize, row: usize, value: T) {
+        self.rows[row][column] = value;
+    }
+}
+
+fn tiled<T>(input: &str, times: usize, cell: impl Fn(char) -> T) -> Vec<Vec<T>> {
+    let lines = input.lines().collect::<Vec<_>>();
+
+    (0..times)
+        .flat_map(|_| lines.iter())
+        .map(|line| line.repeat(times).chars().map(&cell).collect())
+        .collect()
+}
+
+/// Tilt all round rocks (`'O'`) north, one column at a time.
+fn tilt_north<G: Layout<char>>(platform: &mut G) {
+    for column in 0..platform.width() {
+        let mut free_row = 0;
+
+        for row in 0..platform.height() {
+            match platform.get(column, row) {
+                Some('#') => free_row = row + 1,
+                Some('O') => {
+                    platform.update(column, row, '.');
+                    platform.update(column, free_row, 'O');
+                    free_row += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[divan::bench(types = [Grid<char>, NestedGrid<char>])]
+fn platform_tilt<G: Layout<char>>(bencher: Bencher) {
+    let platform = tiled(PLATFORM, 10, |c| c);
+
+    bencher
+        .with_inputs(|| G::from_rows(platform.clone()))
+        .bench_local_values(|mut platform| {
+            tilt_north(&mut platform);
+            platform
+        });
+}
+
+#[divan::bench(types = [Grid<char>, NestedGrid<char>])]
+fn platform_load<G: Layout<char>>(bencher: Bencher) {
+    let platform = G::from_rows(tiled(PLATFORM, 10, |c| c));
+
+    bencher.bench_local(|| {
+        (0..platform.width())
+            .flat_map(|column| (0..platform.height()).map(move |row| (column, row)))
+            .filter(|(column, row)| platform.get(*column, *row) == Some(&'O'))
+            .map(|(_, row)| platform.height() - row)
+            .sum::<usize>()
+    });
+}
+
+/// Walk the guard until they leave the lab or start going in circles, returning the number of
+/// distinct cells visited.
+fn patrol<G: Layout<bool>>(lab: &G, start: (usize, usize)) -> usize {
+    const STEPS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+    let mut visited = G::With::<u8>::with_size(lab.width(), lab.height());
+    let (mut column, mut row) = start;
+    let mut direction = 0;
+    let mut count = 0;
+
+    loop {
+        let seen = *visited.get(column, row).unwrap();
+        if seen & (1 << direction) != 0 {
+            break;
+        }
+        if seen == 0 {
+            count += 1;
+        }
+        visited.update(column, row, seen | (1 << direction));
+
+        let (dx, dy) = STEPS[direction];
+        let (Some(next_column), Some(next_row)) =
+            (column.checked_add_signed(dx), row.checked_add_signed(dy))
+        else {
+            break;
+        };
+
+        match lab.get(next_column, next_row) {
+            None => break,
+            Some(true) => direction = (direction + 1) % 4,
+            Some(false) => (column, row) = (next_column, next_row),
+        }
+    }
+
+    count
+}
+
+#[divan::bench(types = [Grid<bool>, NestedGrid<bool>])]
+fn lab_patrol<G: Layout<bool>>(bencher: Bencher) {
+    let lab = G::from_rows(tiled(LAB, 13, |c| c == '#'));
+    let start = LAB
+        .lines()
+        .enumerate()
+        .find_map(|(row, line)| line.find('^').map(|column| (column, row)))
+        .unwrap();
+
+    bencher.bench_local(|| patrol(black_box(&lab), start));
+}
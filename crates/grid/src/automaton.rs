@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::Grid;
+
+/// Which cells around a cell are its neighbors in a [`CellularAutomaton`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Neighborhood {
+    /// Cells within the given Manhattan distance.
+    VonNeumann(usize),
+    /// Cells within the given Chebyshev distance, i.e. in the surrounding square.
+    Moore(usize),
+}
+
+impl Neighborhood {
+    /// `(dx, dy)` offsets of all neighbors, excluding the cell itself.
+    #[must_use]
+    pub fn offsets(&self) -> Vec<(isize, isize)> {
+        let (radius, max_distance) = match *self {
+            Self::VonNeumann(radius) => (radius as isize, radius),
+            Self::Moore(radius) => (radius as isize, 2 * radius),
+        };
+
+        (-radius..=radius)
+            .flat_map(|dy| (-radius..=radius).map(move |dx| (dx, dy)))
+            .filter(|&(dx, dy)| {
+                (dx, dy) != (0, 0) && dx.unsigned_abs() + dy.unsigned_abs() <= max_distance
+            })
+            .collect()
+    }
+}
+
+/// Steps all cells of a grid synchronously, computing each new cell from its old value and the
+/// old values of its neighbors.
+///
+/// Two grids are kept, so no cells need to be allocated while stepping. Neighbors outside of the
+/// grid are left out, so cells at the edges have fewer of them.
+///
+/// # Examples
+///
+/// ```
+/// use grid::{CellularAutomaton, Grid, Neighborhood};
+///
+/// // Conway's Game of Life, with a blinker
+/// let grid = Grid::parse_chars([".....", "..#..", "..#..", "..#..", "....."], |c| {
+///     Ok::<_, ()>(c == '#')
+/// })
+/// .unwrap();
+/// let mut life = CellularAutomaton::new(grid.clone(), Neighborhood::Moore(1), |alive, neighbors| {
+///     let alive_neighbors = neighbors.iter().filter(|neighbor| ***neighbor).count();
+///
+///     alive_neighbors == 3 || (*alive && alive_neighbors == 2)
+/// });
+///
+/// life.step();
+/// assert_eq!(life.grid().get(1, 2), Some(&true));
+/// assert_eq!(life.grid().get(2, 1), Some(&false));
+///
+/// life.run(1_000_001);
+/// assert_eq!(life.generation(), 1_000_002);
+/// assert!(life.grid() == &grid);
+/// ```
+pub struct CellularAutomaton<T, R> {
+    current: Grid<T>,
+    next: Grid<T>,
+    offsets: Vec<(isize, isize)>,
+    rule: R,
+    generation: usize,
+}
+
+impl<T, R> CellularAutomaton<T, R>
+where
+    T: Clone,
+    R: FnMut(&T, &[&T]) -> T,
+{
+    /// `rule` is given each cell and its neighbors, and returns the cell's next value.
+    pub fn new(grid: Grid<T>, neighborhood: Neighborhood, rule: R) -> Self {
+        Self {
+            next: grid.clone(),
+            current: grid,
+            offsets: neighborhood.offsets(),
+            rule,
+            generation: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    #[must_use]
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+
+    /// Number of steps taken so far.
+    #[must_use]
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn step(&mut self) {
+        let mut neighbors = Vec::with_capacity(self.offsets.len());
+
+        for (column, row) in self.current.coordinates() {
+            neighbors.clear();
+            neighbors.extend(self.offsets.iter().filter_map(|(dx, dy)| {
+                self.current.get(
+                    column.checked_add_signed(*dx)?,
+                    row.checked_add_signed(*dy)?,
+                )
+            }));
+
+            self.next[(column, row)] = (self.rule)(&self.current[(column, row)], &neighbors);
+        }
+
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+    }
+
+    /// Step until a step doesn't change any cell, and return the number of steps that did.
+    ///
+    /// Returns `None` if the grid is still changing after `max_steps` steps, e.g. because it
+    /// oscillates. The automaton is left at that point.
+    pub fn run_until_stable(&mut self, max_steps: usize) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        for changing_steps in 0..max_steps {
+            self.step();
+
+            if self.current == self.next {
+                return Some(changing_steps);
+            }
+        }
+
+        None
+    }
+
+    /// Take `steps` steps. Once a grid repeats, the remaining full cycles are skipped.
+    ///
+    /// Skipping cycles assumes the next grid only depends on the current one. If `rule` keeps
+    /// state of its own (e.g. counting calls), the skipped steps never happen and the result is
+    /// wrong; use [`CellularAutomaton::step`] for such rules.
+    pub fn run(&mut self, steps: usize)
+    where
+        T: Eq + Hash,
+    {
+        let target = self.generation + steps;
+        let mut seen = HashMap::new();
+
+        while self.generation < target {
+            if let Some(first_seen) = seen.insert(self.current.clone(), self.generation) {
+                let cycle_length = self.generation - first_seen;
+                let remaining = (target - self.generation) % cycle_length;
+
+                for _ in 0..remaining {
+                    self.step();
+                }
+                self.generation = target;
+
+                return;
+            }
+
+            self.step();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{example, render};
+
+    use super::*;
+
+    #[test]
+    fn test_automaton() {
+        assert_eq!(Neighborhood::VonNeumann(1).offsets().len(), 4);
+        assert_eq!(Neighborhood::VonNeumann(2).offsets().len(), 12);
+        assert_eq!(Neighborhood::Moore(2).offsets().len(), 24);
+
+        // Every step, spread the maximum to the left/right/up/down neighbors
+        let mut spread =
+            CellularAutomaton::new(example(), Neighborhood::VonNeumann(1), |cell, neighbors| {
+                neighbors.iter().copied().fold(*cell, |a, b| a.max(*b))
+            });
+
+        assert_eq!(spread.run_until_stable(2), None);
+        assert_eq!(spread.run_until_stable(10), Some(1));
+        assert_eq!(spread.generation(), 4);
+        assert_eq!(render(spread.grid()), "666\n666");
+
+        // Alternate between two values, so the cycle is detected after two steps
+        let mut blink =
+            CellularAutomaton::new(example(), Neighborhood::Moore(1), |cell, _| 7 - cell);
+        blink.run(1_001);
+        assert_eq!(blink.generation(), 1_001);
+        assert_eq!(render(blink.grid()), "654\n321");
+        assert_eq!(blink.run_until_stable(100), None);
+        assert_eq!(blink.generation(), 1_101);
+    }
+}
@@ -0,0 +1,456 @@
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use crate::{Connectivity, Grid};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A grid of booleans, packed into `u64` words per row.
+///
+/// Column `c` of a row is bit `c % 64` of word `c / 64`. Bits past the width of the grid are
+/// always kept unset, so whole words can be combined and counted without masking.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// A grid with all cells unset.
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(WORD_BITS);
+
+        Self {
+            width,
+            height,
+            words_per_row,
+            words: vec![0; words_per_row * height],
+        }
+    }
+
+    /// Set every cell of `grid` for which `is_set` holds.
+    pub fn from_grid<T, F: FnMut(&T) -> bool>(grid: &Grid<T>, mut is_set: F) -> Self {
+        let mut bits = Self::new(grid.width(), grid.height());
+
+        for (column, row) in grid.coordinates() {
+            if is_set(&grid[(column, row)]) {
+                bits.set(column, row, true);
+            }
+        }
+
+        bits
+    }
+
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<bool> {
+        let mut grid = Grid::with_size(self.width, self.height);
+
+        for coord in self.iter_ones() {
+            grid[coord] = true;
+        }
+
+        grid
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the given cell is set, or `None` if it's outside of the grid.
+    #[must_use]
+    pub fn get(&self, column: usize, row: usize) -> Option<bool> {
+        (column < self.width && row < self.height).then(|| {
+            let word = self.row_words(row)[column / WORD_BITS];
+
+            word >> (column % WORD_BITS) & 1 == 1
+        })
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the cell is outside of the grid.
+    pub fn set(&mut self, column: usize, row: usize, value: bool) {
+        assert!(
+            column < self.width && row < self.height,
+            "({column}, {row}) is outside of the {}x{} grid",
+            self.width,
+            self.height
+        );
+
+        let word = &mut self.row_words_mut(row)[column / WORD_BITS];
+        let mask = 1 << (column % WORD_BITS);
+
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    /// The packed words of a row; see the type documentation for the layout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is outside of the grid.
+    #[must_use]
+    pub fn row_words(&self, row: usize) -> &[u64] {
+        &self.words[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    fn row_words_mut(&mut self, row: usize) -> &mut [u64] {
+        &mut self.words[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    /// Number of set cells.
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    #[must_use]
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|word| *word != 0)
+    }
+
+    /// Coordinates of all set cells, row by row.
+    pub fn iter_ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, word)| {
+            let row = i / self.words_per_row;
+            let first_column = (i % self.words_per_row) * WORD_BITS;
+            let mut word = *word;
+
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+
+                    (first_column + bit, row)
+                })
+            })
+        })
+    }
+
+    /// Move every cell by `(dx, dy)`. Cells moved off the grid are lost, and vacated cells are
+    /// unset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::{BitGrid, Grid};
+    ///
+    /// let grid = Grid::parse_chars(["#..", "..."], |c| Ok::<_, ()>(c == '#')).unwrap();
+    /// let bits = BitGrid::from_grid(&grid, |cell| *cell);
+    ///
+    /// assert_eq!(bits.shifted(2, 1).iter_ones().collect::<Vec<_>>(), vec![(2, 1)]);
+    /// assert!(!bits.shifted(-1, 0).any());
+    /// ```
+    #[must_use]
+    pub fn shifted(&self, dx: isize, dy: isize) -> Self {
+        let mut shifted = Self::new(self.width, self.height);
+
+        for row in 0..self.height {
+            let Some(source) = row
+                .checked_add_signed(-dy)
+                .filter(|source| *source < self.height)
+            else {
+                continue;
+            };
+
+            shift_words(
+                self.row_words(source),
+                shifted.row_words_mut(row),
+                dx,
+                self.width,
+            );
+        }
+
+        shifted
+    }
+
+    /// For every cell, count its set neighbors, using bit-sliced addition over whole words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::{BitGrid, Connectivity, Grid};
+    ///
+    /// let grid = Grid::parse_chars(["##.", "##.", "..#"], |c| Ok::<_, ()>(c == '#')).unwrap();
+    /// let bits = BitGrid::from_grid(&grid, |cell| *cell);
+    /// let counts = bits.neighbor_counts(Connectivity::Eight);
+    ///
+    /// assert_eq!(counts.get(1, 1), 4);
+    /// assert_eq!(counts.get(2, 0), 2);
+    /// assert_eq!((&bits & &counts.less_than(3)).iter_ones().collect::<Vec<_>>(), vec![(2, 2)]);
+    /// ```
+    #[must_use]
+    pub fn neighbor_counts(&self, connectivity: Connectivity) -> NeighborCounts {
+        let steps: &[(isize, isize)] = match connectivity {
+            Connectivity::Four => &crate::FOUR_WAY_STEPS,
+            Connectivity::Eight => &crate::EIGHT_WAY_STEPS,
+        };
+        let mut planes = vec![Self::new(self.width, self.height); COUNT_PLANES];
+
+        for (dx, dy) in steps {
+            let mut carry = self.shifted(-dx, -dy);
+
+            for plane in &mut planes {
+                if !carry.any() {
+                    break;
+                }
+
+                let sum = &*plane ^ &carry;
+                carry &= &*plane;
+                *plane = sum;
+            }
+        }
+
+        NeighborCounts { planes }
+    }
+
+    fn clear_padding(&mut self) {
+        let padding = self.words_per_row * WORD_BITS - self.width;
+
+        if padding > 0 {
+            let mask = u64::MAX >> padding;
+
+            for row in 0..self.height {
+                let last = self.row_words_mut(row).last_mut().unwrap();
+                *last &= mask;
+            }
+        }
+    }
+
+    fn combine(&mut self, other: &Self, mut op: impl FnMut(&mut u64, u64)) {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "Can't combine a {}x{} grid with a {}x{} grid",
+            self.width,
+            self.height,
+            other.width,
+            other.height
+        );
+
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            op(word, *other);
+        }
+    }
+}
+
+/// Copy `source` into `target`, moving every bit `dx` columns to the right.
+fn shift_words(source: &[u64], target: &mut [u64], dx: isize, width: usize) {
+    let word_shift = dx.unsigned_abs() / WORD_BITS;
+    let bit_shift = dx.unsigned_abs() % WORD_BITS;
+
+    for (i, word) in target.iter_mut().enumerate() {
+        let at = |offset: isize| {
+            i.checked_add_signed(offset)
+                .and_then(|i| source.get(i))
+                .copied()
+                .unwrap_or(0)
+        };
+        let word_shift = word_shift as isize;
+
+        *word = if dx >= 0 {
+            let low = at(-word_shift - 1);
+            let high = at(-word_shift);
+
+            if bit_shift == 0 {
+                high
+            } else {
+                high << bit_shift | low >> (WORD_BITS - bit_shift)
+            }
+        } else {
+            let low = at(word_shift);
+            let high = at(word_shift + 1);
+
+            if bit_shift == 0 {
+                low
+            } else {
+                low >> bit_shift | high << (WORD_BITS - bit_shift)
+            }
+        };
+    }
+
+    let padding = target.len() * WORD_BITS - width;
+    if let Some(last) = target.last_mut()
+        && padding > 0
+    {
+        *last &= u64::MAX >> padding;
+    }
+}
+
+/// Enough bit planes to count up to 15 neighbors.
+const COUNT_PLANES: usize = 4;
+
+/// Per-cell neighbor counts of a [`BitGrid`], as returned by [`BitGrid::neighbor_counts`].
+///
+/// The counts are stored bit-sliced: plane `i` holds bit `i` of every cell's count.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NeighborCounts {
+    planes: Vec<BitGrid>,
+}
+
+impl NeighborCounts {
+    /// The neighbor count of a single cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is outside of the grid.
+    #[must_use]
+    pub fn get(&self, column: usize, row: usize) -> u8 {
+        self.planes
+            .iter()
+            .enumerate()
+            .map(|(i, plane)| u8::from(plane.get(column, row).expect("Cell outside of grid")) << i)
+            .sum()
+    }
+
+    /// All cells with exactly `count` neighbors.
+    #[must_use]
+    pub fn equal_to(&self, count: u8) -> BitGrid {
+        let empty = BitGrid::new(self.planes[0].width, self.planes[0].height);
+
+        if usize::from(count) >> COUNT_PLANES != 0 {
+            return empty;
+        }
+
+        let mut result = !&empty;
+
+        for (i, plane) in self.planes.iter().enumerate() {
+            if count >> i & 1 == 1 {
+                result &= plane;
+            } else {
+                result &= &!plane;
+            }
+        }
+
+        result
+    }
+
+    /// All cells with fewer than `count` neighbors.
+    #[must_use]
+    pub fn less_than(&self, count: u8) -> BitGrid {
+        let mut result = BitGrid::new(self.planes[0].width, self.planes[0].height);
+
+        for smaller in 0..count.min(1 << COUNT_PLANES) {
+            result |= &self.equal_to(smaller);
+        }
+
+        result
+    }
+}
+
+impl fmt::Debug for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "BitGrid {}x{}", self.width, self.height)?;
+
+        for row in 0..self.height {
+            for column in 0..self.width {
+                let c = if self.get(column, row).unwrap() {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut result = self.clone();
+
+        for word in &mut result.words {
+            *word = !*word;
+        }
+        result.clear_padding();
+
+        result
+    }
+}
+
+macro_rules! bit_op {
+    ($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident) => {
+        impl $assign<&BitGrid> for BitGrid {
+            /// # Panics
+            ///
+            /// Panics if the grids have different dimensions.
+            fn $assign_fn(&mut self, other: &BitGrid) {
+                self.combine(other, |word, other| word.$assign_fn(other));
+            }
+        }
+
+        impl $op for &BitGrid {
+            type Output = BitGrid;
+
+            /// # Panics
+            ///
+            /// Panics if the grids have different dimensions.
+            fn $op_fn(self, other: &BitGrid) -> BitGrid {
+                let mut result = self.clone();
+                result.$assign_fn(other);
+
+                result
+            }
+        }
+    };
+}
+
+bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+bit_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_grid() {
+        let grid = Grid::parse_chars([".#.", "###", ".#."], |c| Ok::<_, ()>(c == '#')).unwrap();
+        let bits = BitGrid::from_grid(&grid, |cell| *cell);
+
+        assert_eq!(bits.count_ones(), 5);
+        assert_eq!(bits.to_grid(), grid);
+        assert_eq!((!&bits).count_ones(), 4);
+        assert_eq!((&bits & &bits.shifted(1, 0)).count_ones(), 2);
+        assert_eq!((&bits | &bits.shifted(0, -1)).count_ones(), 7);
+
+        let counts = bits.neighbor_counts(Connectivity::Four);
+        assert_eq!(counts.get(1, 1), 4);
+        assert_eq!(counts.get(0, 0), 2);
+        assert_eq!(counts.equal_to(1).count_ones(), 4);
+        assert_eq!(counts.less_than(3).count_ones(), 8);
+
+        // Shifts carry across word boundaries
+        let mut wide = BitGrid::new(130, 1);
+        wide.set(63, 0, true);
+        wide.set(127, 0, true);
+        assert_eq!(
+            wide.shifted(2, 0).iter_ones().collect::<Vec<_>>(),
+            vec![(65, 0), (129, 0)]
+        );
+        assert_eq!(
+            wide.shifted(-64, 0).iter_ones().collect::<Vec<_>>(),
+            vec![(63, 0)]
+        );
+        assert_eq!(wide.shifted(3, 0).count_ones(), 1);
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, DynamicImage, Frame, ImageError, RgbImage};
+
+use crate::Grid;
+
+/// A colour as red, green and blue components.
+pub type Rgb = [u8; 3];
+
+/// Error returned when exporting grids as images.
+#[derive(Debug)]
+pub enum ExportError {
+    Io(io::Error),
+    Image(ImageError),
+    /// The image would be wider or higher than `u32::MAX` pixels.
+    TooLarge {
+        width: usize,
+        height: usize,
+        scale: u32,
+    },
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportError::Io(error) => write!(f, "I/O error: {error}"),
+            ExportError::Image(error) => write!(f, "Image error: {error}"),
+            ExportError::TooLarge {
+                width,
+                height,
+                scale,
+            } => write!(
+                f,
+                "A {width}x{height} grid is too large to draw at scale {scale}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+impl From<io::Error> for ExportError {
+    fn from(error: io::Error) -> Self {
+        ExportError::Io(error)
+    }
+}
+
+impl From<ImageError> for ExportError {
+    fn from(error: ImageError) -> Self {
+        ExportError::Image(error)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Render this grid as an image, drawing every cell as a `scale`x`scale` square in the colour
+    /// returned by `color`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExportError::TooLarge`] if the image would be wider or higher than `u32::MAX`
+    /// pixels.
+    pub fn to_image<F: FnMut(&T) -> Rgb>(
+        &self,
+        scale: u32,
+        mut color: F,
+    ) -> Result<RgbImage, ExportError> {
+        let pixels = |cells: usize| u32::try_from(cells).ok()?.checked_mul(scale);
+        let (Some(width), Some(height)) = (pixels(self.width), pixels(self.height)) else {
+            return Err(ExportError::TooLarge {
+                width: self.width,
+                height: self.height,
+                scale,
+            });
+        };
+        let colors = self.map(|cell| color(cell));
+
+        Ok(RgbImage::from_fn(width, height, |x, y| {
+            image::Rgb(colors[((x / scale) as usize, (y / scale) as usize)])
+        }))
+    }
+
+    /// Render this grid like [`Grid::to_image`], and save it.
+    ///
+    /// The format is determined by the extension of `path`, e.g. `.png` or `.ppm`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the image is too large, the format isn't supported or the file can't be
+    /// written.
+    pub fn save_image<P, F>(&self, path: P, scale: u32, color: F) -> Result<(), ExportError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&T) -> Rgb,
+    {
+        Ok(self.to_image(scale, color)?.save(path)?)
+    }
+}
+
+enum FrameSink {
+    Gif {
+        encoder: GifEncoder<BufWriter<File>>,
+        delay: Delay,
+    },
+    PngDirectory(PathBuf),
+}
+
+/// Records grids as frames of an animation, e.g. to replay a simulation.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use grid::{FrameRecorder, Grid};
+///
+/// let mut grid = Grid::<bool>::with_size(10, 10);
+/// let mut recorder = FrameRecorder::gif("diagonal.gif", 8, Duration::from_millis(100)).unwrap();
+///
+/// for i in 0..10 {
+///     grid[(i, i)] = true;
+///     recorder
+///         .record(&grid, |set| if *set { [255, 255, 255] } else { [0, 0, 0] })
+///         .unwrap();
+/// }
+/// ```
+pub struct FrameRecorder {
+    sink: FrameSink,
+    scale: u32,
+    frames: usize,
+}
+
+impl FrameRecorder {
+    /// Record into an endlessly looping animated GIF, showing each frame for `frame_duration`.
+    ///
+    /// The GIF is finished when the recorder is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be created.
+    pub fn gif<P: AsRef<Path>>(
+        path: P,
+        scale: u32,
+        frame_duration: Duration,
+    ) -> Result<Self, ExportError> {
+        let mut encoder = GifEncoder::new(BufWriter::new(File::create(path)?));
+        encoder.set_repeat(Repeat::Infinite)?;
+
+        Ok(Self {
+            sink: FrameSink::Gif {
+                encoder,
+                delay: Delay::from_saturating_duration(frame_duration),
+            },
+            scale,
+            frames: 0,
+        })
+    }
+
+    /// Record into numbered PNG files (`00000.png`, `00001.png`, ...) in `directory`, which is
+    /// created if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory can't be created.
+    pub fn png_directory<P: AsRef<Path>>(directory: P, scale: u32) -> Result<Self, ExportError> {
+        fs::create_dir_all(&directory)?;
+
+        Ok(Self {
+            sink: FrameSink::PngDirectory(directory.as_ref().to_path_buf()),
+            scale,
+            frames: 0,
+        })
+    }
+
+    /// Add `grid` as the next frame, rendered like [`Grid::to_image`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frame is too large, or can't be encoded or written.
+    pub fn record<T, F: FnMut(&T) -> Rgb>(
+        &mut self,
+        grid: &Grid<T>,
+        color: F,
+    ) -> Result<(), ExportError> {
+        let image = grid.to_image(self.scale, color)?;
+
+        match &mut self.sink {
+            FrameSink::Gif { encoder, delay } => {
+                let frame = Frame::from_parts(DynamicImage::from(image).into_rgba8(), 0, 0, *delay);
+                encoder.encode_frame(frame)?;
+            }
+            FrameSink::PngDirectory(directory) => {
+                image.save(directory.join(format!("{:05}.png", self.frames)))?;
+            }
+        }
+        self.frames += 1;
+
+        Ok(())
+    }
+
+    /// Number of frames recorded so far.
+    #[must_use]
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::example;
+
+    use super::*;
+
+    #[test]
+    fn test_export() {
+        let grid = example();
+        let gray = |cell: &u8| [cell * 40; 3];
+        let image = grid.to_image(2, gray).unwrap();
+
+        assert_eq!(image.dimensions(), (6, 4));
+        assert_eq!(image.get_pixel(5, 3).0, [240; 3]);
+        assert!(matches!(
+            grid.to_image(u32::MAX / 2, gray),
+            Err(ExportError::TooLarge {
+                width: 3,
+                height: 2,
+                scale: _
+            })
+        ));
+
+        let directory = std::env::temp_dir().join(format!("grid-export-{}", std::process::id()));
+
+        let mut recorder = FrameRecorder::png_directory(&directory, 1).unwrap();
+        recorder.record(&grid, gray).unwrap();
+        recorder.record(&grid.transpose(), gray).unwrap();
+        grid.save_image(directory.join("grid.ppm"), 1, gray)
+            .unwrap();
+        assert_eq!(recorder.frames(), 2);
+        assert!(directory.join("00001.png").exists());
+
+        let mut huge = FrameRecorder::png_directory(&directory, u32::MAX).unwrap();
+        assert!(matches!(
+            huge.record(&grid, gray),
+            Err(ExportError::TooLarge { .. })
+        ));
+        assert!(matches!(
+            grid.save_image(directory.join("huge.png"), u32::MAX, gray),
+            Err(ExportError::TooLarge { .. })
+        ));
+        assert_eq!(huge.frames(), 0);
+
+        let mut recorder = FrameRecorder::gif(
+            directory.join("frames.gif"),
+            1,
+            std::time::Duration::from_millis(10),
+        )
+        .unwrap();
+        recorder.record(&grid, gray).unwrap();
+        drop(recorder);
+        assert!(directory.join("frames.gif").metadata().unwrap().len() > 0);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
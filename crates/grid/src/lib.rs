@@ -1,101 +1,156 @@
-use std::iter::successors;
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut};
+use std::slice;
 use std::{fmt::Debug, hash::Hash};
 
+use coord::Point2;
+
+mod automaton;
+mod bits;
+#[cfg(feature = "export")]
+mod export;
+mod orientation;
+mod rays;
+mod regions;
+mod search;
+mod space;
+mod sparse;
+mod text;
+mod view;
+mod wrapping;
+
+pub use automaton::{CellularAutomaton, Neighborhood};
+pub use bits::{BitGrid, NeighborCounts};
+#[cfg(feature = "export")]
+pub use export::{ExportError, FrameRecorder, Rgb};
+pub use orientation::{Orientation, OrientedGrid, ReflectionLines};
+pub use rays::{EIGHT_WAY_STEPS, FOUR_WAY_STEPS, Ray};
+pub use regions::{Components, Connectivity, Region};
+pub use space::{Connectivity3D, Grid3D};
+pub use sparse::{Bounds, SparseGrid};
+pub use text::{GridParseError, Overlay};
+pub use view::GridView;
+pub use wrapping::{TiledGrid, ToroidalGrid};
+
+/// A rectangular grid of cells, stored row by row in a single contiguous `Vec`.
+///
+/// Cells are addressed by `(column, row)`, with `(0, 0)` being the top-left cell.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     width: usize,
-    rows: Vec<Vec<T>>,
+    height: usize,
+    cells: Vec<T>,
 }
 
 pub struct GridRow<'a, T> {
-    grid: &'a Grid<T>,
-    row: usize,
-    index: usize,
-    index_back: usize,
+    cells: slice::Iter<'a, T>,
 }
 
 impl<'a, T> Iterator for GridRow<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let index = self.index;
-        self.index += 1;
-
-        self.grid.rows.get(self.row).and_then(|row| row.get(index))
+        self.cells.next()
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.len();
-
-        (len, Some(len))
+        self.cells.size_hint()
     }
 }
 
 impl<T> ExactSizeIterator for GridRow<'_, T> {
     fn len(&self) -> usize {
-        self.index_back - self.index
+        self.cells.len()
     }
 }
 
 impl<T> DoubleEndedIterator for GridRow<'_, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.index == self.index_back {
-            None
-        } else {
-            self.index_back -= 1;
-
-            self.grid
-                .rows
-                .get(self.row)
-                .and_then(|row| row.get(self.index_back))
-        }
+        self.cells.next_back()
     }
 }
 
 pub struct GridColumn<'a, T> {
-    grid: &'a Grid<T>,
-    column: usize,
-    index: usize,
-    index_back: usize,
+    cells: StepBy<slice::Iter<'a, T>>,
 }
 
 impl<'a, T> Iterator for GridColumn<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let index = self.index;
-        self.index += 1;
-
-        self.grid
-            .rows
-            .get(index)
-            .and_then(|row| row.get(self.column))
+        self.cells.next()
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.len();
-        (len, Some(len))
+        self.cells.size_hint()
     }
 }
 
 impl<T> ExactSizeIterator for GridColumn<'_, T> {
     fn len(&self) -> usize {
-        self.index_back - self.index
+        self.cells.len()
     }
 }
 
 impl<T> DoubleEndedIterator for GridColumn<'_, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.index == self.index_back {
-            None
-        } else {
-            self.index_back -= 1;
+        self.cells.next_back()
+    }
+}
 
-            self.grid
-                .rows
-                .get(self.index_back)
-                .and_then(|row| row.get(self.column))
-        }
+pub struct GridRowMut<'a, T> {
+    cells: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for GridRowMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cells.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cells.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for GridRowMut<'_, T> {
+    fn len(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl<T> DoubleEndedIterator for GridRowMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cells.next_back()
+    }
+}
+
+pub struct GridColumnMut<'a, T> {
+    cells: StepBy<slice::IterMut<'a, T>>,
+}
+
+impl<'a, T> Iterator for GridColumnMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cells.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cells.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for GridColumnMut<'_, T> {
+    fn len(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl<T> DoubleEndedIterator for GridColumnMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cells.next_back()
     }
 }
 
@@ -110,7 +165,7 @@ impl<T> Iterator for GridCoordinates<'_, T> {
     type Item = (usize, usize);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.row >= self.grid.height() {
+        if self.row >= self.grid.height() || self.grid.width() == 0 {
             None
         } else {
             let coord = (self.column, self.row);
@@ -140,11 +195,21 @@ pub struct LineOfSightNeighbors<'a, T> {
 impl<T> Grid<T> {
     pub fn new(rows: Vec<Vec<T>>) -> Result<Grid<T>, GridCreationError> {
         let width = rows.first().map_or(0, std::vec::Vec::len);
+        let height = rows.len();
 
         if rows.iter().any(|row| row.len() != width) {
             Err(GridCreationError::UnequalRowLengths)
         } else {
-            Ok(Grid { width, rows })
+            let mut cells = Vec::with_capacity(width * height);
+            for row in rows {
+                cells.extend(row);
+            }
+
+            Ok(Grid {
+                width,
+                height,
+                cells,
+            })
         }
     }
 
@@ -153,16 +218,11 @@ impl<T> Grid<T> {
     where
         T: Sized + Default,
     {
-        Self::new(
-            (0..height)
-                .map(|_| {
-                    successors(Some(T::default()), |_| Some(T::default()))
-                        .take(width)
-                        .collect()
-                })
-                .collect(),
-        )
-        .unwrap()
+        Grid {
+            width,
+            height,
+            cells: (0..width * height).map(|_| T::default()).collect(),
+        }
     }
 
     #[must_use]
@@ -172,12 +232,27 @@ impl<T> Grid<T> {
 
     #[must_use]
     pub fn height(&self) -> usize {
-        self.rows.len()
+        self.height
+    }
+
+    /// Index into `cells` of the cell at `(column, row)`, if it's inside the grid.
+    fn index(&self, column: usize, row: usize) -> Option<usize> {
+        self.is_valid_coord(column, row)
+            .then(|| row * self.width + column)
+    }
+
+    fn checked_index(&self, (column, row): (usize, usize)) -> usize {
+        self.index(column, row).unwrap_or_else(|| {
+            panic!(
+                "({column}, {row}) is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
     }
 
     #[must_use]
     pub fn get(&self, column: usize, row: usize) -> Option<&T> {
-        self.rows.get(row).and_then(|row| row.get(column))
+        self.index(column, row).map(|index| &self.cells[index])
     }
 
     #[must_use]
@@ -185,6 +260,38 @@ impl<T> Grid<T> {
         self.get(column, row)
     }
 
+    #[must_use]
+    pub fn get_mut(&mut self, column: usize, row: usize) -> Option<&mut T> {
+        self.index(column, row).map(|index| &mut self.cells[index])
+    }
+
+    #[must_use]
+    pub fn get_coord_mut(&mut self, (column, row): (usize, usize)) -> Option<&mut T> {
+        self.get_mut(column, row)
+    }
+
+    /// Get the cell at `point`, which may have signed coordinates, e.g. a
+    /// [`coord::Coordinate2D`]. Returns `None` for points outside of the grid.
+    #[must_use]
+    pub fn get_point<P: TryInto<usize>>(&self, point: Point2<P>) -> Option<&T> {
+        self.get_coord(point.to_grid_coord()?)
+    }
+
+    #[must_use]
+    pub fn get_point_mut<P: TryInto<usize>>(&mut self, point: Point2<P>) -> Option<&mut T> {
+        self.get_coord_mut(point.to_grid_coord()?)
+    }
+
+    /// Iterate over all cells, row by row.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Iterate mutably over all cells, row by row.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
     #[must_use]
     pub fn coordinates(&self) -> GridCoordinates<'_, T> {
         GridCoordinates {
@@ -196,26 +303,99 @@ impl<T> Grid<T> {
 
     #[must_use]
     pub fn row(&self, row: usize) -> GridRow<'_, T> {
+        let cells = if row < self.height() {
+            &self.cells[row * self.width..(row + 1) * self.width]
+        } else {
+            &[]
+        };
+
         GridRow {
-            grid: self,
-            row,
-            index: 0,
-            index_back: self.width(),
+            cells: cells.iter(),
         }
     }
 
     #[must_use]
     pub fn column(&self, column: usize) -> GridColumn<'_, T> {
-        GridColumn {
-            grid: self,
-            column,
-            index: 0,
-            index_back: self.height(),
+        let cells = if column < self.width() && self.height > 0 {
+            self.cells[column..].iter().step_by(self.width)
+        } else {
+            [].iter().step_by(1)
+        };
+
+        GridColumn { cells }
+    }
+
+    #[must_use]
+    pub fn row_mut(&mut self, row: usize) -> GridRowMut<'_, T> {
+        let cells = if row < self.height() {
+            &mut self.cells[row * self.width..(row + 1) * self.width]
+        } else {
+            &mut []
+        };
+
+        GridRowMut {
+            cells: cells.iter_mut(),
         }
     }
 
+    #[must_use]
+    pub fn column_mut(&mut self, column: usize) -> GridColumnMut<'_, T> {
+        let cells = if column < self.width() && self.height > 0 {
+            self.cells[column..].iter_mut().step_by(self.width)
+        } else {
+            [].iter_mut().step_by(1)
+        };
+
+        GridColumnMut { cells }
+    }
+
+    /// Set the cell at `(column, row)` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(column, row)` is outside of the grid. See [`Grid::try_update`] for a
+    /// non-panicking version.
     pub fn update(&mut self, column: usize, row: usize, value: T) {
-        self.rows[row][column] = value;
+        self[(column, row)] = value;
+    }
+
+    /// Set the cell at `(column, row)` to `value`, handing `value` back if `(column, row)` is
+    /// outside of the grid.
+    pub fn try_update(&mut self, column: usize, row: usize, value: T) -> Result<(), T> {
+        match self.get_mut(column, row) {
+            Some(cell) => {
+                *cell = value;
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Swap the cells at `a` and `b`, both given as `(column, row)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either coordinate is outside of the grid.
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let a = self.checked_index(a);
+        let b = self.checked_index(b);
+
+        self.cells.swap(a, b);
+    }
+
+    /// Create a new grid of the same size, with `f` applied to every cell.
+    #[must_use]
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Apply `f` to every cell.
+    pub fn map_in_place<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.cells.iter_mut().for_each(f);
     }
 
     #[must_use]
@@ -305,14 +485,60 @@ impl<T> Grid<T> {
 
 impl<T: Debug> Debug for Grid<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in 0..self.height() {
-            writeln!(f, "{:?}", self.rows[row])?;
+        for row in self.cells.chunks(self.width.max(1)) {
+            writeln!(f, "{row:?}")?;
         }
 
         Ok(())
     }
 }
 
+/// Index by `(column, row)`, panicking if that's outside of the grid.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: (usize, usize)) -> &Self::Output {
+        &self.cells[self.checked_index(coord)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, coord: (usize, usize)) -> &mut Self::Output {
+        let index = self.checked_index(coord);
+
+        &mut self.cells[index]
+    }
+}
+
+/// Index using a [`Point2`], where `x` is the column and `y` the row.
+///
+/// # Examples
+///
+/// ```
+/// use coord::Point2;
+/// use direction::Direction;
+/// use grid::Grid;
+///
+/// let grid = Grid::parse_chars(["abc", "def"], Ok::<_, ()>).unwrap();
+/// let position = Point2::new(1, 1);
+///
+/// assert_eq!(grid[position], 'e');
+/// assert_eq!(grid[position + Direction::Up], 'b');
+/// ```
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &Self::Output {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut Self::Output {
+        &mut self[(point.x, point.y)]
+    }
+}
+
 impl<T> FromIterator<Vec<T>> for Grid<T> {
     fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
         let rows = iter.into_iter().collect();
@@ -324,8 +550,114 @@ impl<T> FromIterator<Vec<T>> for Grid<T> {
 impl<T: Clone> Grid<T> {
     #[must_use]
     pub fn transpose(&self) -> Self {
-        (0..self.width())
-            .map(|column| self.column(column).cloned().collect::<Vec<_>>())
-            .collect()
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width())
+                .flat_map(|column| self.column(column).cloned())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use direction::Direction;
+
+    use super::*;
+
+    pub(crate) fn example() -> Grid<u8> {
+        Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    pub(crate) fn render(grid: &Grid<u8>) -> String {
+        grid.overlay(|digit| char::from(b'0' + digit)).to_string()
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = example();
+
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(
+            grid.row(1).rev().copied().collect::<Vec<_>>(),
+            vec![6, 5, 4]
+        );
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(grid.column(2).len(), 2);
+        assert_eq!(grid.row(2).count(), 0);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.get(2, 1), Some(&6));
+        assert_eq!(grid.get(3, 0), None);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let transposed = example().transpose();
+
+        assert_eq!(
+            transposed,
+            Grid::new(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap()
+        );
+        assert_eq!(transposed.transpose(), example());
+    }
+
+    #[test]
+    fn test_without_rows() {
+        let mut grid = Grid::<u8>::with_size(3, 0);
+
+        assert_eq!(grid.column(1).count(), 0);
+        assert_eq!(grid.column_mut(1).count(), 0);
+
+        let transposed = grid.transpose();
+        assert_eq!((transposed.width(), transposed.height()), (0, 3));
+        assert_eq!(transposed.row(1).count(), 0);
+    }
+
+    #[test]
+    fn test_mutation() {
+        let mut grid = example();
+
+        grid.swap((0, 0), (2, 1));
+        grid[(1, 0)] += 10;
+        *grid.get_mut(1, 1).unwrap() = 0;
+        grid.row_mut(1).for_each(|cell| *cell *= 2);
+        grid.column_mut(2).rev().for_each(|cell| *cell += 1);
+
+        assert_eq!(
+            grid,
+            Grid::new(vec![vec![6, 12, 4], vec![8, 0, 3]]).unwrap()
+        );
+        assert_eq!(grid.try_update(3, 0, 7), Err(7));
+        assert_eq!(grid.try_update(2, 0, 7), Ok(()));
+        assert_eq!(
+            grid.map(|cell| cell % 2 == 0)
+                .iter()
+                .filter(|even| **even)
+                .count(),
+            4
+        );
+
+        grid.map_in_place(|cell| *cell = 1);
+        assert!(grid.iter().all(|cell| *cell == 1));
+    }
+
+    #[test]
+    fn test_points() {
+        let mut grid = example();
+        let mut position = Point2::new(0, 0);
+
+        position += Direction::Right;
+        grid[position + Direction::Down] = 0;
+        assert_eq!(render(&grid), "123\n406");
+        assert_eq!(grid.get_point(coord::Coordinate2D::new(2, 1)), Some(&6));
+        assert_eq!(grid.get_point(coord::Coordinate2D::new(-1, 1)), None);
+        assert_eq!(grid.get_point(Point2::new(3_u8, 0)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 0) is outside of the 3x2 grid")]
+    fn test_index_out_of_bounds() {
+        let _ = example()[(3, 0)];
     }
 }
@@ -0,0 +1,274 @@
+use crate::Grid;
+
+/// One of the eight ways a grid can be rotated and/or mirrored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Identity,
+    /// Rotated a quarter turn clockwise.
+    RotateCw,
+    /// Rotated half a turn.
+    Rotate180,
+    /// Rotated a quarter turn counterclockwise.
+    RotateCcw,
+    /// Mirrored left to right.
+    FlipHorizontal,
+    /// Mirrored top to bottom.
+    FlipVertical,
+    /// Mirrored along the top-left to bottom-right diagonal.
+    Transpose,
+    /// Mirrored along the top-right to bottom-left diagonal.
+    AntiTranspose,
+}
+
+impl Orientation {
+    pub const ALL: [Orientation; 8] = [
+        Orientation::Identity,
+        Orientation::RotateCw,
+        Orientation::Rotate180,
+        Orientation::RotateCcw,
+        Orientation::FlipHorizontal,
+        Orientation::FlipVertical,
+        Orientation::Transpose,
+        Orientation::AntiTranspose,
+    ];
+
+    /// Whether this orientation swaps the grid's width and height.
+    #[must_use]
+    pub fn swaps_dimensions(&self) -> bool {
+        matches!(
+            self,
+            Orientation::RotateCw
+                | Orientation::RotateCcw
+                | Orientation::Transpose
+                | Orientation::AntiTranspose
+        )
+    }
+}
+
+/// A zero-copy view of a [`Grid`] in another [`Orientation`].
+///
+/// Create one using [`Grid::oriented`].
+pub struct OrientedGrid<'a, T> {
+    grid: &'a Grid<T>,
+    orientation: Orientation,
+}
+
+impl<T> Clone for OrientedGrid<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for OrientedGrid<'_, T> {}
+
+impl<'a, T> OrientedGrid<'a, T> {
+    #[must_use]
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        if self.orientation.swaps_dimensions() {
+            self.grid.height()
+        } else {
+            self.grid.width()
+        }
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        if self.orientation.swaps_dimensions() {
+            self.grid.width()
+        } else {
+            self.grid.height()
+        }
+    }
+
+    /// Translate `(column, row)` in this view into coordinates of the underlying grid.
+    #[must_use]
+    pub fn to_base_coord(&self, column: usize, row: usize) -> Option<(usize, usize)> {
+        if column >= self.width() || row >= self.height() {
+            return None;
+        }
+
+        let last_column = self.grid.width() - 1;
+        let last_row = self.grid.height() - 1;
+
+        Some(match self.orientation {
+            Orientation::Identity => (column, row),
+            Orientation::RotateCw => (row, last_row - column),
+            Orientation::Rotate180 => (last_column - column, last_row - row),
+            Orientation::RotateCcw => (last_column - row, column),
+            Orientation::FlipHorizontal => (last_column - column, row),
+            Orientation::FlipVertical => (column, last_row - row),
+            Orientation::Transpose => (row, column),
+            Orientation::AntiTranspose => (last_column - row, last_row - column),
+        })
+    }
+
+    #[must_use]
+    pub fn get(&self, column: usize, row: usize) -> Option<&'a T> {
+        self.to_base_coord(column, row)
+            .and_then(|(column, row)| self.grid.get(column, row))
+    }
+
+    pub fn row(&self, row: usize) -> impl DoubleEndedIterator<Item = &'a T> + use<'a, T> {
+        let view = *self;
+
+        (0..self.width()).filter_map(move |column| view.get(column, row))
+    }
+
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &'a T> + use<'a, T> {
+        let view = *self;
+
+        (0..self.height()).filter_map(move |row| view.get(column, row))
+    }
+
+    /// All coordinates of this view, row by row.
+    pub fn coordinates(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width();
+
+        (0..self.height()).flat_map(move |row| (0..width).map(move |column| (column, row)))
+    }
+
+    /// Copy this view into a new grid.
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        (0..self.height())
+            .map(|row| self.row(row).cloned().collect())
+            .collect()
+    }
+}
+
+/// Axes of symmetry found by [`Grid::find_reflection_lines`].
+///
+/// Axes are identified by the number of rows above (for horizontal ones) or columns to the left
+/// (for vertical ones) of them.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ReflectionLines {
+    pub horizontal: Vec<usize>,
+    pub vertical: Vec<usize>,
+}
+
+impl<T> Grid<T> {
+    /// A view of this grid in `orientation`, without copying any cells.
+    #[must_use]
+    pub fn oriented(&self, orientation: Orientation) -> OrientedGrid<'_, T> {
+        OrientedGrid {
+            grid: self,
+            orientation,
+        }
+    }
+
+    /// Find all axes of symmetry, allowing for exactly `smudges` cells not matching their mirror
+    /// image.
+    ///
+    /// Only the part of the grid that has a mirror image on the other side of the axis is
+    /// considered, so any axis between two rows or columns can qualify.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::Grid;
+    ///
+    /// let grid = Grid::parse_chars(["#..#.", "#..##"], Ok::<_, ()>).unwrap();
+    /// let reflections = grid.find_reflection_lines(0);
+    ///
+    /// assert_eq!(reflections.vertical, vec![2]);
+    /// assert_eq!(reflections.horizontal, vec![]);
+    /// assert_eq!(grid.find_reflection_lines(1).horizontal, vec![1]);
+    /// ```
+    #[must_use]
+    pub fn find_reflection_lines(&self, smudges: usize) -> ReflectionLines
+    where
+        T: PartialEq,
+    {
+        let find = |length: usize, mismatches: &dyn Fn(usize, usize) -> usize| {
+            (1..length)
+                .filter(|axis| {
+                    let mut total = 0;
+
+                    for offset in 0..(*axis).min(length - axis) {
+                        total += mismatches(axis - 1 - offset, axis + offset);
+                        if total > smudges {
+                            return false;
+                        }
+                    }
+
+                    total == smudges
+                })
+                .collect()
+        };
+
+        ReflectionLines {
+            horizontal: find(self.height(), &|a, b| {
+                self.row(a).zip(self.row(b)).filter(|(a, b)| a != b).count()
+            }),
+            vertical: find(self.width(), &|a, b| {
+                self.column(a)
+                    .zip(self.column(b))
+                    .filter(|(a, b)| a != b)
+                    .count()
+            }),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Rotate a quarter turn clockwise.
+    #[must_use]
+    pub fn rotate_cw(&self) -> Self {
+        self.oriented(Orientation::RotateCw).to_grid()
+    }
+
+    /// Rotate a quarter turn counterclockwise.
+    #[must_use]
+    pub fn rotate_ccw(&self) -> Self {
+        self.oriented(Orientation::RotateCcw).to_grid()
+    }
+
+    /// Mirror left to right.
+    #[must_use]
+    pub fn flip_horizontal(&self) -> Self {
+        self.oriented(Orientation::FlipHorizontal).to_grid()
+    }
+
+    /// Mirror top to bottom.
+    #[must_use]
+    pub fn flip_vertical(&self) -> Self {
+        self.oriented(Orientation::FlipVertical).to_grid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{example, render};
+
+    use super::*;
+
+    #[test]
+    fn test_orientations() {
+        let grid = example();
+
+        assert_eq!(render(&grid.rotate_cw()), "41\n52\n63");
+        assert_eq!(render(&grid.rotate_ccw()), "36\n25\n14");
+        assert_eq!(render(&grid.flip_horizontal()), "321\n654");
+        assert_eq!(render(&grid.flip_vertical()), "456\n123");
+        assert_eq!(
+            grid.rotate_cw().rotate_cw(),
+            grid.oriented(Orientation::Rotate180).to_grid()
+        );
+        assert_eq!(
+            grid.oriented(Orientation::Transpose).to_grid(),
+            grid.transpose()
+        );
+        assert_eq!(
+            grid.oriented(Orientation::AntiTranspose).to_grid(),
+            grid.rotate_cw().flip_vertical()
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use std::iter::FusedIterator;
+
+use direction::Direction8;
+
+use crate::Grid;
+
+/// `(dx, dy)` steps towards the orthogonal neighbors: up, down, left and right.
+pub const FOUR_WAY_STEPS: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+/// `(dx, dy)` steps towards all eight neighbors: the orthogonal ones first, then up-left,
+/// up-right, down-left and down-right. These are the [`Direction8::delta`]s.
+pub const EIGHT_WAY_STEPS: [(isize, isize); 8] = [
+    Direction8::Up.delta(),
+    Direction8::Down.delta(),
+    Direction8::Left.delta(),
+    Direction8::Right.delta(),
+    Direction8::UpLeft.delta(),
+    Direction8::UpRight.delta(),
+    Direction8::DownLeft.delta(),
+    Direction8::DownRight.delta(),
+];
+
+/// Lazily walks a grid in a straight line, yielding `((column, row), &cell)` until it leaves the
+/// grid.
+///
+/// Create one using [`Grid::ray`].
+#[derive(Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    position: Option<(usize, usize)>,
+    step: (isize, isize),
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (column, row) = self.position?;
+        let next = column
+            .checked_add_signed(self.step.0)
+            .zip(row.checked_add_signed(self.step.1))
+            .filter(|(column, row)| self.grid.is_valid_coord(*column, *row));
+
+        self.position = next;
+        next.map(|coord| (coord, &self.grid[coord]))
+    }
+}
+
+impl<T> FusedIterator for Ray<'_, T> {}
+
+impl<T> Grid<T> {
+    /// Walk from `start` (exclusive) in steps of `(dx, dy)`, until leaving the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `(0, 0)`, as that would never leave the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::Grid;
+    ///
+    /// let grid = Grid::parse_chars(["abc", "def", "ghi"], Ok::<_, ()>).unwrap();
+    ///
+    /// assert_eq!(grid.ray((0, 0), (1, 1)).map(|(_, c)| c).collect::<String>(), "ei");
+    /// assert_eq!(grid.ray((2, 0), (-2, 1)).collect::<Vec<_>>(), vec![((0, 1), &'d')]);
+    /// ```
+    #[must_use]
+    pub fn ray(&self, start: (usize, usize), step: (isize, isize)) -> Ray<'_, T> {
+        assert_ne!(step, (0, 0), "A ray needs a non-zero step");
+
+        Ray {
+            grid: self,
+            position: Some(start),
+            step,
+        }
+    }
+
+    /// Like [`Grid::ray`], but stops after the first cell for which `stop` holds (including
+    /// that cell).
+    ///
+    /// This is what can be seen from `start` when looking past cells until one blocks the view.
+    pub fn ray_until<P: FnMut(&T) -> bool>(
+        &self,
+        start: (usize, usize),
+        step: (isize, isize),
+        mut stop: P,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let mut stopped = false;
+
+        self.ray(start, step).take_while(move |(_, cell)| {
+            let take = !stopped;
+            stopped = stopped || stop(cell);
+
+            take
+        })
+    }
+
+    /// Rays from `start` in all [eight directions](EIGHT_WAY_STEPS).
+    pub fn rays(&self, start: (usize, usize)) -> impl Iterator<Item = Ray<'_, T>> {
+        EIGHT_WAY_STEPS
+            .into_iter()
+            .map(move |step| self.ray(start, step))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::example;
+
+    use super::*;
+
+    #[test]
+    fn test_rays() {
+        let grid = example();
+        let cells = |ray: Ray<'_, u8>| ray.map(|(_, cell)| *cell).collect::<Vec<_>>();
+
+        assert_eq!(cells(grid.ray((0, 0), (1, 0))), vec![2, 3]);
+        assert_eq!(cells(grid.ray((2, 1), (-1, -1))), vec![2]);
+        assert_eq!(cells(grid.ray((0, 0), (0, -1))), vec![]);
+        assert_eq!(
+            grid.ray_until((0, 1), (1, 0), |cell| *cell == 5)
+                .collect::<Vec<_>>(),
+            vec![((1, 1), &5)]
+        );
+        assert_eq!(
+            grid.rays((1, 0)).map(|ray| ray.count()).collect::<Vec<_>>(),
+            vec![0, 1, 1, 1, 0, 0, 1, 1]
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use std::collections::VecDeque;
+
+use crate::Grid;
+
+/// Which cells count as adjacent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Horizontally and vertically adjacent cells.
+    Four,
+    /// Horizontally, vertically and diagonally adjacent cells.
+    Eight,
+}
+
+/// A connected region of cells, as found by [`Grid::components`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    /// Index of this region in [`Components::regions`], and its label in [`Components::labels`].
+    pub label: usize,
+    /// All cells in the region, in the order they were found.
+    pub cells: Vec<(usize, usize)>,
+    /// Number of cell edges bordering other regions or the outside of the grid.
+    ///
+    /// Only edges count, so with [`Connectivity::Eight`] the cells touching diagonally are still
+    /// separated by their full edges: the perimeter is the sum of the perimeters of the
+    /// horizontally and vertically connected pieces of the region.
+    pub perimeter: usize,
+    /// Number of corners of the region's outline(s), which is also its number of straight sides.
+    ///
+    /// Like [`Region::perimeter`], this follows cell edges and ignores diagonal connections.
+    pub corners: usize,
+    /// Top-left `(column, row)` of the region's bounding box.
+    pub top_left: (usize, usize),
+    /// Bottom-right `(column, row)` of the region's bounding box.
+    pub bottom_right: (usize, usize),
+}
+
+impl Region {
+    #[must_use]
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The number of straight sides of the region's outline(s), which equals the number of
+    /// corners.
+    ///
+    /// Sides follow cell edges, so a region connected through diagonals has the sides of each of
+    /// its horizontally and vertically connected pieces.
+    #[must_use]
+    pub fn sides(&self) -> usize {
+        self.corners
+    }
+}
+
+/// Result of labelling all connected components of a grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Components {
+    /// For every cell, the label of the region it belongs to.
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+impl<T> Grid<T> {
+    /// Coordinates adjacent to `(column, row)` according to `connectivity`.
+    pub fn get_connected_neighbors_iter(
+        &self,
+        column: usize,
+        row: usize,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let include_diagonals = connectivity == Connectivity::Eight;
+
+        self.get_neighbors_incl_diagonals_iter(column, row)
+            .filter(move |(x, y)| include_diagonals || *x == column || *y == row)
+    }
+
+    /// Find all cells connected to `seed` through cells for which `include` holds.
+    ///
+    /// Cells are returned in breadth-first order, starting at `seed`. If `include` doesn't hold
+    /// for `seed` itself, nothing is returned.
+    pub fn flood_fill<F: FnMut((usize, usize), &T) -> bool>(
+        &self,
+        seed: (usize, usize),
+        connectivity: Connectivity,
+        mut include: F,
+    ) -> Vec<(usize, usize)> {
+        let mut seen = Grid::<bool>::with_size(self.width(), self.height());
+        let mut queue = VecDeque::new();
+        let mut result = vec![];
+
+        if self.get_coord(seed).is_some_and(|cell| include(seed, cell)) {
+            seen[seed] = true;
+            queue.push_back(seed);
+        }
+
+        while let Some(coord @ (column, row)) = queue.pop_front() {
+            result.push(coord);
+
+            for neighbor in self.get_connected_neighbors_iter(column, row, connectivity) {
+                if !seen[neighbor] && include(neighbor, &self[neighbor]) {
+                    seen[neighbor] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Label all connected components of equal cells.
+    #[must_use]
+    pub fn components(&self, connectivity: Connectivity) -> Components
+    where
+        T: PartialEq,
+    {
+        self.components_by(connectivity, |a, b| a == b)
+    }
+
+    /// Label all connected components, where adjacent cells `a` and `b` are in the same region
+    /// if `same_region(a, b)` holds.
+    ///
+    /// `same_region` should be symmetric, or the resulting regions depend on the order in which
+    /// cells are visited.
+    #[must_use]
+    pub fn components_by<F: Fn(&T, &T) -> bool>(
+        &self,
+        connectivity: Connectivity,
+        same_region: F,
+    ) -> Components {
+        let mut labels = Grid::<Option<usize>>::with_size(self.width(), self.height());
+        let mut regions = vec![];
+
+        for seed in self.coordinates() {
+            if labels[seed].is_some() {
+                continue;
+            }
+
+            let label = regions.len();
+            let mut cells = vec![seed];
+            let mut index = 0;
+            labels[seed] = Some(label);
+
+            while let Some(&(column, row)) = cells.get(index) {
+                index += 1;
+
+                for neighbor in self.get_connected_neighbors_iter(column, row, connectivity) {
+                    if labels[neighbor].is_none()
+                        && same_region(&self[(column, row)], &self[neighbor])
+                    {
+                        labels[neighbor] = Some(label);
+                        cells.push(neighbor);
+                    }
+                }
+            }
+
+            regions.push(cells);
+        }
+
+        let labels = labels.map(|label| label.unwrap());
+        let regions = regions
+            .into_iter()
+            .enumerate()
+            .map(|(label, cells)| region_metrics(&labels, label, cells))
+            .collect();
+
+        Components { labels, regions }
+    }
+}
+
+fn region_metrics(labels: &Grid<usize>, label: usize, cells: Vec<(usize, usize)>) -> Region {
+    let member = |column: usize, row: usize, dx: isize, dy: isize| {
+        column
+            .checked_add_signed(dx)
+            .zip(row.checked_add_signed(dy))
+            .and_then(|(column, row)| labels.get(column, row))
+            == Some(&label)
+    };
+
+    let mut perimeter = 0;
+    let mut corners = 0;
+    let mut top_left = cells[0];
+    let mut bottom_right = cells[0];
+
+    for &(column, row) in &cells {
+        perimeter += [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .filter(|(dx, dy)| !member(column, row, *dx, *dy))
+            .count();
+
+        // There's a corner between this cell and a diagonal if both orthogonal cells in between
+        // are outside the region (convex), or both are inside and the diagonal isn't (concave).
+        corners += [(-1, -1), (1, -1), (-1, 1), (1, 1)]
+            .into_iter()
+            .filter(|(dx, dy)| {
+                let horizontal = member(column, row, *dx, 0);
+                let vertical = member(column, row, 0, *dy);
+
+                horizontal == vertical && !(horizontal && member(column, row, *dx, *dy))
+            })
+            .count();
+
+        top_left = (top_left.0.min(column), top_left.1.min(row));
+        bottom_right = (bottom_right.0.max(column), bottom_right.1.max(row));
+    }
+
+    Region {
+        label,
+        cells,
+        perimeter,
+        corners,
+        top_left,
+        bottom_right,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regions() {
+        let grid = Grid::parse_chars(["AAB", "ABB", "CBA"], Ok::<_, ()>).unwrap();
+        let components = grid.components(Connectivity::Four);
+        let sizes = components
+            .regions
+            .iter()
+            .map(|region| (region.area(), region.perimeter, region.sides()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(sizes, vec![(3, 8, 6), (4, 10, 8), (1, 4, 4), (1, 4, 4)]);
+        assert_eq!(components.labels[(1, 2)], 1);
+        assert_eq!(components.regions[1].top_left, (1, 0));
+        assert_eq!(components.regions[1].bottom_right, (2, 2));
+        assert_eq!(
+            Grid::parse_chars(["AB", "BA"], Ok::<_, ()>)
+                .unwrap()
+                .components(Connectivity::Eight)
+                .regions
+                .len(),
+            2
+        );
+        assert_eq!(
+            grid.flood_fill((0, 0), Connectivity::Eight, |_, cell| *cell != 'C'),
+            vec![
+                (0, 0),
+                (1, 0),
+                (0, 1),
+                (1, 1),
+                (2, 0),
+                (2, 1),
+                (1, 2),
+                (2, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diagonal_regions() {
+        // The `A`s are a single region when connecting diagonally, but their outline still
+        // consists of three separate squares
+        let grid = Grid::parse_chars(["A.A", ".A."], Ok::<_, ()>).unwrap();
+        let components = grid.components(Connectivity::Eight);
+        let region = &components.regions[0];
+
+        assert_eq!(components.regions.len(), 2);
+        assert_eq!(region.area(), 3);
+        assert_eq!(region.perimeter, 12);
+        assert_eq!(region.sides(), 12);
+        assert_eq!((region.top_left, region.bottom_right), ((0, 0), (2, 1)));
+    }
+}
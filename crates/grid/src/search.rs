@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+use std::iter::successors;
+
+use crate::Grid;
+
+/// For every cell, the cell it was reached from.
+type Predecessors = Grid<Option<(usize, usize)>>;
+
+impl<T> Grid<T> {
+    /// Breadth-first search over orthogonal steps, from all of `sources` at once.
+    ///
+    /// `passable(from, to)` determines whether a step from cell `from` to neighboring cell `to`
+    /// is allowed. If `reverse` is set, steps are followed backwards: `passable` is called as
+    /// `passable(to, from)`, so distances are towards `sources` rather than from them.
+    ///
+    /// Returns the distance and predecessor for every cell reached. If `target` is reached, the
+    /// search stops early.
+    fn bfs<I, F>(
+        &self,
+        sources: I,
+        mut passable: F,
+        reverse: bool,
+        target: Option<(usize, usize)>,
+    ) -> (Grid<Option<usize>>, Predecessors)
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut distances = Grid::<Option<usize>>::with_size(self.width(), self.height());
+        let mut predecessors =
+            Grid::<Option<(usize, usize)>>::with_size(self.width(), self.height());
+        let mut queue = VecDeque::new();
+
+        for source in sources {
+            if let Some(distance @ None) = distances.get_coord_mut(source) {
+                *distance = Some(0);
+                queue.push_back(source);
+            }
+        }
+
+        while let Some(current @ (column, row)) = queue.pop_front() {
+            if Some(current) == target {
+                break;
+            }
+
+            let distance = distances[current].unwrap() + 1;
+
+            for neighbor in self.get_neighbors_iter(column, row) {
+                let (from, to) = if reverse {
+                    (&self[neighbor], &self[current])
+                } else {
+                    (&self[current], &self[neighbor])
+                };
+
+                if distances[neighbor].is_none() && passable(from, to) {
+                    distances[neighbor] = Some(distance);
+                    predecessors[neighbor] = Some(current);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        (distances, predecessors)
+    }
+
+    /// The number of orthogonal steps it takes to reach every cell from the nearest of `sources`,
+    /// or `None` for cells that can't be reached.
+    ///
+    /// `passable(from, to)` determines whether a step from cell `from` to neighboring cell `to`
+    /// is allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::Grid;
+    ///
+    /// let maze = Grid::parse_chars(["..#", "#..", "..#"], Ok::<_, ()>).unwrap();
+    /// let distances = maze.bfs_distances([(0, 0)], |_, to| *to != '#');
+    ///
+    /// assert_eq!(distances[(1, 2)], Some(3));
+    /// assert_eq!(distances[(2, 0)], None);
+    /// ```
+    pub fn bfs_distances<I, F>(&self, sources: I, passable: F) -> Grid<Option<usize>>
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.bfs(sources, passable, false, None).0
+    }
+
+    /// The number of orthogonal steps it takes to get from every cell to the nearest of
+    /// `targets`, or `None` for cells that can't reach any of them.
+    ///
+    /// This searches backwards from `targets`, which is useful when there are many potential
+    /// starting positions. As with [`Grid::bfs_distances`], `passable(from, to)` determines
+    /// whether a step from `from` to `to` is allowed.
+    pub fn bfs_distances_to<I, F>(&self, targets: I, passable: F) -> Grid<Option<usize>>
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.bfs(targets, passable, true, None).0
+    }
+
+    /// A shortest path from any of `sources` to `target`, including both ends, or `None` if
+    /// `target` can't be reached.
+    pub fn bfs_path<I, F>(
+        &self,
+        sources: I,
+        target: (usize, usize),
+        passable: F,
+    ) -> Option<Vec<(usize, usize)>>
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+        F: FnMut(&T, &T) -> bool,
+    {
+        let (distances, predecessors) = self.bfs(sources, passable, false, Some(target));
+        distances.get_coord(target).copied().flatten()?;
+
+        let mut path = successors(Some(target), |coord| predecessors[*coord]).collect::<Vec<_>>();
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::example;
+
+    use super::*;
+
+    #[test]
+    fn test_bfs() {
+        // Can only step to cells at most one higher
+        let heights = example();
+        let climbable = |from: &u8, to: &u8| *to <= from + 1;
+
+        assert_eq!(
+            heights.bfs_distances([(0, 0)], climbable),
+            Grid::new(vec![
+                vec![Some(0), Some(1), Some(2)],
+                vec![None, None, None]
+            ])
+            .unwrap()
+        );
+        assert_eq!(
+            heights.bfs_distances_to([(1, 1)], climbable),
+            Grid::new(vec![
+                vec![None, None, None],
+                vec![Some(1), Some(0), Some(1)]
+            ])
+            .unwrap()
+        );
+        assert_eq!(
+            heights.bfs_path([(0, 0), (0, 1)], (2, 1), climbable),
+            Some(vec![(0, 1), (1, 1), (2, 1)])
+        );
+        assert_eq!(heights.bfs_path([(0, 0)], (2, 1), climbable), None);
+    }
+}
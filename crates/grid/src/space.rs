@@ -0,0 +1,275 @@
+use std::collections::VecDeque;
+use std::ops::{Index, IndexMut};
+
+type Coord3D = (usize, usize, usize);
+
+/// Which cells count as adjacent in a [`Grid3D`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Connectivity3D {
+    /// Cells sharing a face.
+    Six,
+    /// Cells sharing a face or an edge.
+    Eighteen,
+    /// Cells sharing a face, an edge or a corner.
+    TwentySix,
+}
+
+impl Connectivity3D {
+    /// `(dx, dy, dz)` offsets of all neighbors.
+    pub fn offsets(self) -> impl Iterator<Item = (isize, isize, isize)> {
+        let max_changed_axes = match self {
+            Self::Six => 1,
+            Self::Eighteen => 2,
+            Self::TwentySix => 3,
+        };
+
+        (-1..=1)
+            .flat_map(|dz| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| (dx, dy, dz))))
+            .filter(move |&(dx, dy, dz)| {
+                let changed_axes = [dx, dy, dz].into_iter().filter(|d| *d != 0).count();
+
+                (1..=max_changed_axes).contains(&changed_axes)
+            })
+    }
+}
+
+/// A dense three-dimensional grid, indexed by `(x, y, z)`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid3D<T> {
+    width: usize,
+    height: usize,
+    depth: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid3D<T> {
+    #[must_use]
+    pub fn with_size(width: usize, height: usize, depth: usize) -> Self
+    where
+        T: Default,
+    {
+        Self {
+            width,
+            height,
+            depth,
+            cells: (0..width * height * depth).map(|_| T::default()).collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    #[must_use]
+    pub fn is_valid_coord(&self, x: usize, y: usize, z: usize) -> bool {
+        x < self.width && y < self.height && z < self.depth
+    }
+
+    fn index(&self, x: usize, y: usize, z: usize) -> Option<usize> {
+        self.is_valid_coord(x, y, z)
+            .then(|| (z * self.height + y) * self.width + x)
+    }
+
+    fn checked_index(&self, (x, y, z): Coord3D) -> usize {
+        self.index(x, y, z).unwrap_or_else(|| {
+            panic!(
+                "({x}, {y}, {z}) is outside of the {}x{}x{} grid",
+                self.width, self.height, self.depth
+            )
+        })
+    }
+
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize, z: usize) -> Option<&T> {
+        self.index(x, y, z).map(|index| &self.cells[index])
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, x: usize, y: usize, z: usize) -> Option<&mut T> {
+        self.index(x, y, z).map(|index| &mut self.cells[index])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// All coordinates, with `x` changing fastest and `z` slowest.
+    pub fn coordinates(&self) -> impl Iterator<Item = Coord3D> + use<T> {
+        let (width, height, depth) = (self.width, self.height, self.depth);
+
+        (0..depth)
+            .flat_map(move |z| (0..height).flat_map(move |y| (0..width).map(move |x| (x, y, z))))
+    }
+
+    /// Coordinates of all neighbors of `(x, y, z)` inside the grid.
+    pub fn get_neighbors_iter(
+        &self,
+        x: usize,
+        y: usize,
+        z: usize,
+        connectivity: Connectivity3D,
+    ) -> impl Iterator<Item = Coord3D> + use<'_, T> {
+        connectivity.offsets().filter_map(move |(dx, dy, dz)| {
+            let neighbor = (
+                x.checked_add_signed(dx)?,
+                y.checked_add_signed(dy)?,
+                z.checked_add_signed(dz)?,
+            );
+
+            self.is_valid_coord(neighbor.0, neighbor.1, neighbor.2)
+                .then_some(neighbor)
+        })
+    }
+
+    /// Number of faces of cells for which `is_solid` holds, that don't touch another such cell.
+    ///
+    /// Faces on the outside of the grid count as well.
+    pub fn surface_area<F: Fn(&T) -> bool>(&self, is_solid: F) -> usize {
+        self.count_faces(&is_solid, |neighbor| !is_solid(&self[neighbor]))
+    }
+
+    /// Like [`Grid3D::surface_area`], but only counts faces reachable from outside the grid,
+    /// ignoring those of air pockets enclosed by solid cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::Grid3D;
+    ///
+    /// // A 3x3x3 cube with a hole in the middle
+    /// let mut cube = Grid3D::<bool>::from_points((0..27).map(|i| (i % 3, i / 3 % 3, i / 9)));
+    /// cube[(1, 1, 1)] = false;
+    ///
+    /// assert_eq!(cube.surface_area(|solid| *solid), 6 * 9 + 6);
+    /// assert_eq!(cube.exterior_surface_area(|solid| *solid), 6 * 9);
+    /// ```
+    pub fn exterior_surface_area<F: Fn(&T) -> bool>(&self, is_solid: F) -> usize {
+        let mut exterior = Grid3D::<bool>::with_size(self.width, self.height, self.depth);
+        let mut queue = self
+            .coordinates()
+            .filter(|&(x, y, z)| {
+                x == 0
+                    || y == 0
+                    || z == 0
+                    || x == self.width - 1
+                    || y == self.height - 1
+                    || z == self.depth - 1
+            })
+            .filter(|coord| !is_solid(&self[*coord]))
+            .collect::<VecDeque<_>>();
+
+        for coord in &queue {
+            exterior[*coord] = true;
+        }
+
+        while let Some((x, y, z)) = queue.pop_front() {
+            for neighbor in self.get_neighbors_iter(x, y, z, Connectivity3D::Six) {
+                if !exterior[neighbor] && !is_solid(&self[neighbor]) {
+                    exterior[neighbor] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        self.count_faces(&is_solid, |neighbor| exterior[neighbor])
+    }
+
+    /// Count faces of solid cells, which are on the outside of the grid or for which
+    /// `is_exposed` holds for the neighbor on the other side.
+    fn count_faces<F, E>(&self, is_solid: &F, is_exposed: E) -> usize
+    where
+        F: Fn(&T) -> bool,
+        E: Fn(Coord3D) -> bool,
+    {
+        self.coordinates()
+            .filter(|coord| is_solid(&self[*coord]))
+            .map(|(x, y, z)| {
+                let neighbors = self.get_neighbors_iter(x, y, z, Connectivity3D::Six);
+                let mut inside = 0;
+                let mut exposed = 0;
+
+                for neighbor in neighbors {
+                    inside += 1;
+                    if is_exposed(neighbor) {
+                        exposed += 1;
+                    }
+                }
+
+                exposed + 6 - inside
+            })
+            .sum()
+    }
+}
+
+impl Grid3D<bool> {
+    /// The smallest grid containing all `points`, with only those set.
+    #[must_use]
+    pub fn from_points<I: IntoIterator<Item = Coord3D>>(points: I) -> Self {
+        let points = points.into_iter().collect::<Vec<_>>();
+        let size =
+            |axis: fn(&Coord3D) -> usize| points.iter().map(axis).max().map_or(0, |max| max + 1);
+        let mut grid = Self::with_size(size(|p| p.0), size(|p| p.1), size(|p| p.2));
+
+        for point in points {
+            grid[point] = true;
+        }
+
+        grid
+    }
+}
+
+impl<T> Index<Coord3D> for Grid3D<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord3D) -> &T {
+        &self.cells[self.checked_index(coord)]
+    }
+}
+
+impl<T> IndexMut<Coord3D> for Grid3D<T> {
+    fn index_mut(&mut self, coord: Coord3D) -> &mut T {
+        let index = self.checked_index(coord);
+
+        &mut self.cells[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_3d() {
+        assert_eq!(Connectivity3D::Six.offsets().count(), 6);
+        assert_eq!(Connectivity3D::Eighteen.offsets().count(), 18);
+        assert_eq!(Connectivity3D::TwentySix.offsets().count(), 26);
+
+        let mut grid = Grid3D::<u8>::with_size(2, 3, 4);
+        grid[(1, 2, 3)] = 7;
+        assert_eq!(grid.get(1, 2, 3), Some(&7));
+        assert_eq!(grid.get(2, 2, 3), None);
+        assert_eq!(grid.coordinates().count(), 24);
+        assert_eq!(grid.coordinates().last(), Some((1, 2, 3)));
+        assert_eq!(
+            grid.get_neighbors_iter(0, 0, 0, Connectivity3D::TwentySix)
+                .count(),
+            7
+        );
+
+        // Two cubes sharing a face
+        let droplet = Grid3D::<bool>::from_points([(1, 1, 1), (2, 1, 1)]);
+        assert_eq!(droplet.surface_area(|lava| *lava), 10);
+        assert_eq!(droplet.exterior_surface_area(|lava| *lava), 10);
+    }
+}
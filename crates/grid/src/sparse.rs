@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+use std::collections::hash_map;
+
+use crate::Grid;
+use crate::wrapping::{DIAGONAL_DELTAS, NEIGHBOR_DELTAS};
+
+/// The smallest rectangle containing a set of signed coordinates (inclusive on all sides).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+}
+
+impl Bounds {
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.min_x.abs_diff(self.max_x) as usize + 1
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.min_y.abs_diff(self.max_y) as usize + 1
+    }
+
+    #[must_use]
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    fn extend(&mut self, x: i64, y: i64) {
+        self.min_x = self.min_x.min(x);
+        self.min_y = self.min_y.min(y);
+        self.max_x = self.max_x.max(x);
+        self.max_y = self.max_y.max(y);
+    }
+}
+
+/// An unbounded grid with signed coordinates, which only stores cells that have been set.
+///
+/// All other cells have the default value given on creation. The bounding box of all cells that
+/// were ever set is tracked, so the grid can be turned into a dense [`Grid`] for rendering.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+    default: T,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    #[must_use]
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Create a sparse grid from `grid`, with its top-left cell at `origin`.
+    ///
+    /// Cells equal to `default` aren't stored.
+    #[must_use]
+    pub fn from_grid(grid: Grid<T>, origin: (i64, i64), default: T) -> Self
+    where
+        T: PartialEq,
+    {
+        let width = grid.width();
+        let mut result = Self::new(default);
+
+        for (index, cell) in grid.cells.into_iter().enumerate() {
+            if cell != result.default {
+                result.set(
+                    origin.0 + (index % width) as i64,
+                    origin.1 + (index / width) as i64,
+                    cell,
+                );
+            }
+        }
+
+        result
+    }
+
+    #[must_use]
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// The cell at `(x, y)`, or the default value if it was never set.
+    #[must_use]
+    pub fn get(&self, x: i64, y: i64) -> &T {
+        self.cells.get(&(x, y)).unwrap_or(&self.default)
+    }
+
+    /// Whether the cell at `(x, y)` was set (and not removed since).
+    #[must_use]
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.cells.contains_key(&(x, y))
+    }
+
+    /// Set the cell at `(x, y)`, returning its previous value if it was set.
+    pub fn set(&mut self, x: i64, y: i64, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(x, y),
+            None => {
+                self.bounds = Some(Bounds {
+                    min_x: x,
+                    min_y: y,
+                    max_x: x,
+                    max_y: y,
+                });
+            }
+        }
+
+        self.cells.insert((x, y), value)
+    }
+
+    /// Mutable access to the cell at `(x, y)`, setting it to the default value first if needed.
+    pub fn get_mut(&mut self, x: i64, y: i64) -> &mut T
+    where
+        T: Clone,
+    {
+        if !self.contains(x, y) {
+            self.set(x, y, self.default.clone());
+        }
+
+        self.cells.get_mut(&(x, y)).unwrap()
+    }
+
+    /// Reset the cell at `(x, y)` to the default value, returning its value if it was set.
+    ///
+    /// This doesn't shrink the bounds.
+    pub fn remove(&mut self, x: i64, y: i64) -> Option<T> {
+        self.cells.remove(&(x, y))
+    }
+
+    /// The bounding box of all cells ever set, or `None` if no cell was ever set.
+    #[must_use]
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// The number of cells that are set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Iterate over all cells that are set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.cells.iter().map(|(coord, cell)| (*coord, cell))
+    }
+
+    /// Coordinates of all cells that are set, in no particular order.
+    pub fn coordinates(&self) -> hash_map::Keys<'_, (i64, i64), T> {
+        self.cells.keys()
+    }
+
+    pub fn get_neighbors_iter(&self, x: i64, y: i64) -> impl Iterator<Item = (i64, i64)> + use<T> {
+        NEIGHBOR_DELTAS
+            .into_iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    pub fn get_neighbors_incl_diagonals_iter(
+        &self,
+        x: i64,
+        y: i64,
+    ) -> impl Iterator<Item = (i64, i64)> + use<T> {
+        NEIGHBOR_DELTAS
+            .into_iter()
+            .chain(DIAGONAL_DELTAS)
+            .map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    /// Copy the cells within the bounds into a dense grid, along with the coordinates of its
+    /// top-left cell.
+    ///
+    /// Returns an empty grid if no cell was ever set.
+    #[must_use]
+    pub fn to_grid(&self) -> (Grid<T>, (i64, i64))
+    where
+        T: Clone,
+    {
+        let Some(bounds) = self.bounds else {
+            return (Grid::new(vec![]).unwrap(), (0, 0));
+        };
+
+        let grid = (bounds.min_y..=bounds.max_y)
+            .map(|y| {
+                (bounds.min_x..=bounds.max_x)
+                    .map(|x| self.get(x, y).clone())
+                    .collect()
+            })
+            .collect();
+
+        (grid, (bounds.min_x, bounds.min_y))
+    }
+}
+
+impl<T> Extend<((i64, i64), T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = ((i64, i64), T)>>(&mut self, iter: I) {
+        for ((x, y), value) in iter {
+            self.set(x, y, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::render;
+
+    use super::*;
+
+    #[test]
+    fn test_sparse() {
+        let mut sparse = SparseGrid::new(0u8);
+
+        sparse.set(-1, 2, 5);
+        *sparse.get_mut(1, 0) += 3;
+        sparse.set(0, 1, 0);
+        assert_eq!(sparse.remove(0, 1), Some(0));
+
+        assert_eq!(sparse.get(-1, 2), &5);
+        assert_eq!(sparse.get(7, 7), &0);
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(
+            sparse.bounds(),
+            Some(Bounds {
+                min_x: -1,
+                min_y: 0,
+                max_x: 1,
+                max_y: 2
+            })
+        );
+
+        let (grid, origin) = sparse.to_grid();
+        assert_eq!(origin, (-1, 0));
+        assert_eq!(render(&grid), "003\n000\n500");
+        assert_eq!(SparseGrid::from_grid(grid, origin, 0).iter().count(), 2);
+    }
+}
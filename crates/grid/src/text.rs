@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+
+use coord::Point2;
+use direction::Direction;
+
+use crate::Grid;
+
+/// Error returned by [`Grid::parse_chars`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridParseError<E> {
+    /// The character at `(column, row)` couldn't be converted into a cell.
+    InvalidCell {
+        column: usize,
+        row: usize,
+        c: char,
+        error: E,
+    },
+    /// `row` doesn't have as many cells as the rows before it.
+    UnequalRowLengths {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl<E: Display> Display for GridParseError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridParseError::InvalidCell {
+                column,
+                row,
+                c,
+                error,
+            } => write!(f, "Invalid cell {c:?} at ({column}, {row}): {error}"),
+            GridParseError::UnequalRowLengths {
+                row,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Row {row} has {actual} cells, while previous rows have {expected}"
+            ),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug + Display> std::error::Error for GridParseError<E> {}
+
+impl<T> Grid<T> {
+    /// Parse a grid with a cell per character, converting characters into cells using `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::{Grid, GridParseError};
+    ///
+    /// let parse_cell = |c| match c {
+    ///     '#' => Ok(true),
+    ///     '.' => Ok(false),
+    ///     _ => Err("unknown cell"),
+    /// };
+    /// let grid = Grid::parse_chars(["#.", ".#"], parse_cell).unwrap();
+    ///
+    /// assert_eq!(grid.overlay(|lit| if *lit { '#' } else { '.' }).to_string(), "#.\n.#");
+    /// assert_eq!(
+    ///     Grid::parse_chars(["#.", ".?"], parse_cell),
+    ///     Err(GridParseError::InvalidCell { column: 1, row: 1, c: '?', error: "unknown cell" })
+    /// );
+    /// ```
+    pub fn parse_chars<S, I, E, F>(lines: I, mut f: F) -> Result<Self, GridParseError<E>>
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = S>,
+        F: FnMut(char) -> Result<T, E>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (row, line) in lines.into_iter().enumerate() {
+            let row_start = cells.len();
+
+            for (column, c) in line.as_ref().chars().enumerate() {
+                cells.push(f(c).map_err(|error| GridParseError::InvalidCell {
+                    column,
+                    row,
+                    c,
+                    error,
+                })?);
+            }
+
+            let row_length = cells.len() - row_start;
+            match width {
+                None => width = Some(row_length),
+                Some(expected) if expected != row_length => {
+                    return Err(GridParseError::UnequalRowLengths {
+                        row,
+                        expected,
+                        actual: row_length,
+                    });
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Start rendering this grid, converting cells into characters using `to_char`.
+    ///
+    /// The returned [`Overlay`] can highlight cells before being displayed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::Grid;
+    ///
+    /// let grid = Grid::parse_chars(["123", "456"], |c| c.to_digit(10).ok_or(c)).unwrap();
+    /// let rendering = grid
+    ///     .overlay(|digit| char::from_digit(*digit, 10).unwrap())
+    ///     .path([(0, 0), (2, 0), (2, 1)])
+    ///     .highlight([(0, 1)], '#')
+    ///     .to_string();
+    ///
+    /// assert_eq!(rendering, "1>>\n#5v");
+    /// ```
+    pub fn overlay<F: Fn(&T) -> char>(&self, to_char: F) -> Overlay<'_, T, F> {
+        Overlay {
+            grid: self,
+            to_char,
+            marks: HashMap::new(),
+        }
+    }
+}
+
+/// Renders one line per row, without a trailing newline.
+impl<T> Display for Grid<T>
+where
+    T: Clone,
+    char: From<T>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.overlay(|cell| char::from(cell.clone())).fmt(f)
+    }
+}
+
+/// A rendering of a [`Grid`], where some cells are drawn as a different character.
+///
+/// Create one using [`Grid::overlay`].
+pub struct Overlay<'a, T, F> {
+    grid: &'a Grid<T>,
+    to_char: F,
+    marks: HashMap<(usize, usize), char>,
+}
+
+impl<T, F> Overlay<'_, T, F> {
+    /// Draw all `coords` (given as `(column, row)`) as `c`.
+    #[must_use]
+    pub fn highlight<I: IntoIterator<Item = (usize, usize)>>(mut self, coords: I, c: char) -> Self {
+        self.marks
+            .extend(coords.into_iter().map(|coord| (coord, c)));
+
+        self
+    }
+
+    /// Draw `path` using arrows (`^v<>`).
+    ///
+    /// Every cell after the first one gets the arrow of the direction it was entered from. Each
+    /// step must be horizontal or vertical, but doesn't need to be to an adjacent cell: the
+    /// cells in between are drawn too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a step is diagonal.
+    #[must_use]
+    pub fn path<I: IntoIterator<Item = (usize, usize)>>(mut self, path: I) -> Self {
+        let mut path = path.into_iter();
+        let Some(mut from) = path.next().map(Point2::from) else {
+            return self;
+        };
+
+        for to in path.map(Point2::from) {
+            if from == to {
+                continue;
+            }
+
+            let direction = Direction::determine(&from, &to)
+                .unwrap_or_else(|| panic!("Step from {from:?} to {to:?} is diagonal"));
+            let distance = from.manhattan_distance(&to);
+
+            for coord in direction
+                .advance_with_intermediate_coords(&from, distance)
+                .unwrap()
+            {
+                self.marks.insert(coord.into(), char::from(direction));
+            }
+
+            from = to;
+        }
+
+        self
+    }
+}
+
+impl<T, F: Fn(&T) -> char> Display for Overlay<'_, T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.grid.height() {
+            if row != 0 {
+                f.write_char('\n')?;
+            }
+
+            for column in 0..self.grid.width() {
+                let c = self
+                    .marks
+                    .get(&(column, row))
+                    .copied()
+                    .unwrap_or_else(|| (self.to_char)(&self.grid[(column, row)]));
+
+                f.write_char(c)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text() {
+        let grid = Grid::parse_chars(["ab", "cd"], Ok::<_, ()>).unwrap();
+
+        assert_eq!(grid.to_string(), "ab\ncd");
+        assert_eq!(
+            Grid::parse_chars(["ab", "c"], Ok::<_, ()>),
+            Err(GridParseError::UnequalRowLengths {
+                row: 1,
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+}
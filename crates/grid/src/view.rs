@@ -0,0 +1,274 @@
+use std::ops::Index;
+
+use crate::{Grid, GridParseError};
+
+/// A zero-copy view of a rectangular part of a [`Grid`].
+///
+/// Create one using [`Grid::view`] or [`Grid::windows`].
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: (usize, usize),
+    width: usize,
+    height: usize,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    /// Coordinates of the view's top-left cell in the underlying grid.
+    #[must_use]
+    pub fn origin(&self) -> (usize, usize) {
+        self.origin
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Translate `(column, row)` in this view into coordinates of the underlying grid.
+    #[must_use]
+    pub fn to_base_coord(&self, column: usize, row: usize) -> Option<(usize, usize)> {
+        (column < self.width && row < self.height)
+            .then(|| (self.origin.0 + column, self.origin.1 + row))
+    }
+
+    #[must_use]
+    pub fn get(&self, column: usize, row: usize) -> Option<&'a T> {
+        self.to_base_coord(column, row)
+            .and_then(|(column, row)| self.grid.get(column, row))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the row is outside of the view.
+    pub fn row(&self, row: usize) -> impl DoubleEndedIterator<Item = &'a T> + use<'a, T> {
+        assert!(
+            row < self.height,
+            "Row {row} is outside of the {}x{} view",
+            self.width,
+            self.height
+        );
+
+        let start = (self.origin.1 + row) * self.grid.width + self.origin.0;
+
+        self.grid.cells[start..start + self.width].iter()
+    }
+
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &'a T> + use<'a, T> {
+        let view = *self;
+
+        (0..self.height).filter_map(move |row| view.get(column, row))
+    }
+
+    /// All cells of this view, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        let view = *self;
+
+        (0..self.height).flat_map(move |row| view.row(row))
+    }
+
+    /// All coordinates of this view, row by row.
+    pub fn coordinates(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (column, row)))
+    }
+
+    /// Copy this view into a new grid.
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (column, row): (usize, usize)) -> &T {
+        self.get(column, row).unwrap_or_else(|| {
+            panic!(
+                "({column}, {row}) is outside of the {}x{} view",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// A view of the `width`x`height` rectangle with its top-left corner at `(column, row)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rectangle doesn't fit inside the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::Grid;
+    ///
+    /// let grid = Grid::parse_chars(["abcd", "efgh", "ijkl"], Ok::<_, ()>).unwrap();
+    /// let view = grid.view(1, 1, 2, 2);
+    ///
+    /// assert_eq!(view[(0, 0)], 'f');
+    /// assert_eq!(view.iter().collect::<String>(), "fgjk");
+    /// assert_eq!(view.get(2, 0), None);
+    /// ```
+    #[must_use]
+    pub fn view(&self, column: usize, row: usize, width: usize, height: usize) -> GridView<'_, T> {
+        assert!(
+            column + width <= self.width && row + height <= self.height,
+            "A {width}x{height} view at ({column}, {row}) doesn't fit in the {}x{} grid",
+            self.width,
+            self.height
+        );
+
+        GridView {
+            grid: self,
+            origin: (column, row),
+            width,
+            height,
+        }
+    }
+
+    /// Views of all `width`x`height` rectangles inside the grid, row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::Grid;
+    ///
+    /// let grid = Grid::parse_chars(["abc", "def"], Ok::<_, ()>).unwrap();
+    /// let windows = grid
+    ///     .windows(2, 2)
+    ///     .map(|window| window.iter().collect::<String>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(windows, vec!["abde", "bcef"]);
+    /// ```
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = GridView<'_, T>> {
+        let columns = (self.width + 1).saturating_sub(width);
+        let rows = (self.height + 1).saturating_sub(height);
+
+        (0..rows).flat_map(move |row| {
+            (0..columns).map(move |column| self.view(column, row, width, height))
+        })
+    }
+
+    /// Parse several grids separated by blank lines, converting characters into cells using `f`.
+    ///
+    /// Rows in errors are line numbers in the whole input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::Grid;
+    ///
+    /// let grids = Grid::parse_blocks(["#.", ".#", "", "###"], |c| Ok::<_, ()>(c == '#')).unwrap();
+    ///
+    /// assert_eq!(grids.len(), 2);
+    /// assert_eq!(grids[1].width(), 3);
+    /// ```
+    pub fn parse_blocks<S, I, E, F>(lines: I, mut f: F) -> Result<Vec<Self>, GridParseError<E>>
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = S>,
+        F: FnMut(char) -> Result<T, E>,
+    {
+        let mut grids = vec![];
+        let mut block = vec![];
+        let mut block_start = 0;
+
+        let mut finish_block = |block: &mut Vec<S>, block_start| {
+            if !block.is_empty() {
+                let grid =
+                    Self::parse_chars(block.drain(..), &mut f).map_err(|error| match error {
+                        GridParseError::InvalidCell {
+                            column,
+                            row,
+                            c,
+                            error,
+                        } => GridParseError::InvalidCell {
+                            column,
+                            row: block_start + row,
+                            c,
+                            error,
+                        },
+                        GridParseError::UnequalRowLengths {
+                            row,
+                            expected,
+                            actual,
+                        } => GridParseError::UnequalRowLengths {
+                            row: block_start + row,
+                            expected,
+                            actual,
+                        },
+                    })?;
+                grids.push(grid);
+            }
+
+            Ok(())
+        };
+
+        for (line_number, line) in lines.into_iter().enumerate() {
+            if line.as_ref().is_empty() {
+                finish_block(&mut block, block_start)?;
+                block_start = line_number + 1;
+            } else {
+                block.push(line);
+            }
+        }
+        finish_block(&mut block, block_start)?;
+
+        Ok(grids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{example, render};
+
+    use super::*;
+
+    #[test]
+    fn test_views() {
+        let grid = example();
+        let view = grid.view(1, 0, 2, 2);
+
+        assert_eq!(view.origin(), (1, 0));
+        assert_eq!(view.row(1).copied().collect::<Vec<_>>(), vec![5, 6]);
+        assert_eq!(view.column(0).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(view.to_base_coord(1, 1), Some((2, 1)));
+        assert_eq!(render(&view.to_grid()), "23\n56");
+        assert_eq!(grid.windows(2, 1).count(), 4);
+        assert_eq!(grid.windows(4, 1).count(), 0);
+
+        let blocks = Grid::parse_blocks(["12", "", "", "3", "45"], Ok::<_, ()>);
+        assert_eq!(
+            blocks,
+            Err(GridParseError::UnequalRowLengths {
+                row: 4,
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+}
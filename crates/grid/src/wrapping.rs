@@ -0,0 +1,200 @@
+use crate::Grid;
+
+pub(crate) const NEIGHBOR_DELTAS: [(i64, i64); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+pub(crate) const DIAGONAL_DELTAS: [(i64, i64); 4] = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
+const ALL_DELTAS: [(i64, i64); 8] = [
+    NEIGHBOR_DELTAS[0],
+    NEIGHBOR_DELTAS[1],
+    NEIGHBOR_DELTAS[2],
+    NEIGHBOR_DELTAS[3],
+    DIAGONAL_DELTAS[0],
+    DIAGONAL_DELTAS[1],
+    DIAGONAL_DELTAS[2],
+    DIAGONAL_DELTAS[3],
+];
+
+/// Map signed `(x, y)` onto the coordinates of a `width` by `height` grid, wrapping around.
+///
+/// Returns `None` if the grid is empty, as there's nothing to wrap onto.
+fn wrap((x, y): (i64, i64), width: usize, height: usize) -> Option<(usize, usize)> {
+    if width == 0 || height == 0 {
+        None
+    } else {
+        Some((
+            x.rem_euclid(width as i64) as usize,
+            y.rem_euclid(height as i64) as usize,
+        ))
+    }
+}
+
+/// A view of a [`Grid`] tiled infinitely in all directions.
+///
+/// Coordinates are signed, with `(0, 0)` being the top-left cell of the original grid. Every
+/// copy of the grid is a distinct area, so neighbors are never wrapped.
+///
+/// Create one using [`Grid::tiled`].
+pub struct TiledGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> TiledGrid<'a, T> {
+    /// The coordinates in the underlying grid that `(x, y)` maps onto, or `None` if the grid is
+    /// empty.
+    #[must_use]
+    pub fn base_coord(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        wrap((x, y), self.grid.width(), self.grid.height())
+    }
+
+    /// Which copy of the grid `(x, y)` is in, with `(0, 0)` being the original, or `None` if the
+    /// grid is empty.
+    #[must_use]
+    pub fn tile(&self, x: i64, y: i64) -> Option<(i64, i64)> {
+        if self.is_empty() {
+            None
+        } else {
+            Some((
+                x.div_euclid(self.grid.width() as i64),
+                y.div_euclid(self.grid.height() as i64),
+            ))
+        }
+    }
+
+    /// Returns `None` only if the underlying grid is empty.
+    #[must_use]
+    pub fn get(&self, x: i64, y: i64) -> Option<&'a T> {
+        self.grid.get_coord(self.base_coord(x, y)?)
+    }
+
+    /// The neighbors of `(x, y)`, or none if the grid is empty.
+    pub fn get_neighbors_iter(&self, x: i64, y: i64) -> impl Iterator<Item = (i64, i64)> + use<T> {
+        let deltas: &[(i64, i64)] = if self.is_empty() {
+            &[]
+        } else {
+            &NEIGHBOR_DELTAS
+        };
+
+        deltas.iter().map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    /// Like [`TiledGrid::get_neighbors_iter`], including diagonal neighbors.
+    pub fn get_neighbors_incl_diagonals_iter(
+        &self,
+        x: i64,
+        y: i64,
+    ) -> impl Iterator<Item = (i64, i64)> + use<T> {
+        let deltas: &[(i64, i64)] = if self.is_empty() { &[] } else { &ALL_DELTAS };
+
+        deltas.iter().map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.grid.width() == 0 || self.grid.height() == 0
+    }
+}
+
+/// A view of a [`Grid`] whose edges wrap around, making it a torus.
+///
+/// Neighbors of cells on an edge are found on the opposite edge. Signed coordinates can be used
+/// to access cells, and are wrapped the same way.
+///
+/// Create one using [`Grid::toroidal`].
+pub struct ToroidalGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> ToroidalGrid<'a, T> {
+    /// Wrap signed `(x, y)` onto the grid, or `None` if the grid is empty.
+    #[must_use]
+    pub fn wrap(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        wrap((x, y), self.grid.width(), self.grid.height())
+    }
+
+    /// Returns `None` only if the underlying grid is empty.
+    #[must_use]
+    pub fn get(&self, x: i64, y: i64) -> Option<&'a T> {
+        self.grid.get_coord(self.wrap(x, y)?)
+    }
+
+    /// The neighbors of `(column, row)`, or none if the grid is empty.
+    pub fn get_neighbors_iter(
+        &self,
+        column: usize,
+        row: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.grid.width(), self.grid.height());
+
+        NEIGHBOR_DELTAS
+            .into_iter()
+            .filter_map(move |(dx, dy)| wrap((column as i64 + dx, row as i64 + dy), width, height))
+    }
+
+    /// Like [`ToroidalGrid::get_neighbors_iter`], including diagonal neighbors.
+    pub fn get_neighbors_incl_diagonals_iter(
+        &self,
+        column: usize,
+        row: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.grid.width(), self.grid.height());
+
+        ALL_DELTAS
+            .into_iter()
+            .filter_map(move |(dx, dy)| wrap((column as i64 + dx, row as i64 + dy), width, height))
+    }
+}
+
+impl<T> Grid<T> {
+    /// A view of this grid, tiled infinitely in all directions.
+    #[must_use]
+    pub fn tiled(&self) -> TiledGrid<'_, T> {
+        TiledGrid { grid: self }
+    }
+
+    /// A view of this grid with its edges wrapping around.
+    #[must_use]
+    pub fn toroidal(&self) -> ToroidalGrid<'_, T> {
+        ToroidalGrid { grid: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::example;
+
+    use super::*;
+
+    #[test]
+    fn test_wrapping() {
+        let grid = example();
+        let tiled = grid.tiled();
+        let toroidal = grid.toroidal();
+
+        assert_eq!(tiled.get(-1, -1), Some(&6));
+        assert_eq!(tiled.tile(-1, 2), Some((-1, 1)));
+        assert_eq!(tiled.get_neighbors_iter(0, 0).count(), 4);
+        assert!(tiled.get_neighbors_iter(0, 0).any(|coord| coord == (-1, 0)));
+        assert_eq!(toroidal.get(3, 5), Some(&4));
+        assert_eq!(
+            toroidal.get_neighbors_iter(0, 0).collect::<Vec<_>>(),
+            vec![(2, 0), (1, 0), (0, 1), (0, 1)]
+        );
+        assert_eq!(toroidal.get_neighbors_incl_diagonals_iter(2, 1).count(), 8);
+    }
+
+    #[test]
+    fn test_wrapping_empty_grid() {
+        for grid in [Grid::<u8>::with_size(0, 3), Grid::<u8>::with_size(3, 0)] {
+            let tiled = grid.tiled();
+            let toroidal = grid.toroidal();
+
+            assert_eq!(tiled.get(1, 1), None);
+            assert_eq!(tiled.base_coord(1, 1), None);
+            assert_eq!(tiled.tile(1, 1), None);
+            assert_eq!(tiled.get_neighbors_iter(0, 0).count(), 0);
+            assert_eq!(tiled.get_neighbors_incl_diagonals_iter(0, 0).count(), 0);
+            assert_eq!(toroidal.get(1, 1), None);
+            assert_eq!(toroidal.wrap(1, 1), None);
+            assert_eq!(toroidal.get_neighbors_iter(0, 0).count(), 0);
+            assert_eq!(toroidal.get_neighbors_incl_diagonals_iter(0, 0).count(), 0);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use quote::format_ident;
+use syn::punctuated::Punctuated;
+use syn::{Expr, ItemMod, LitStr, Path as SynPath, Token};
+
+/// Suffix of the sidecar file holding the expected answers for an example input.
+const EXPECTED_SUFFIX: &str = ".expected";
+
+/// Arguments to the `example_tests` attribute.
+pub struct Args {
+    dir: String,
+    parse: SynPath,
+    part_1: SynPath,
+    part_2: SynPath,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Self {
+            dir: "src".to_string(),
+            parse: syn::parse_quote!(parse),
+            part_1: syn::parse_quote!(part_1),
+            part_2: syn::parse_quote!(part_2),
+        }
+    }
+}
+
+impl Args {
+    pub fn parse_meta(&mut self, meta: syn::meta::ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("dir") {
+            self.dir = meta.value()?.parse::<LitStr>()?.value();
+        } else if meta.path.is_ident("parse") {
+            self.parse = meta.value()?.parse()?;
+        } else if meta.path.is_ident("part_1") {
+            self.part_1 = meta.value()?.parse()?;
+        } else if meta.path.is_ident("part_2") {
+            self.part_2 = meta.value()?.parse()?;
+        } else {
+            return Err(meta.error("expected one of `dir`, `parse`, `part_1` or `part_2`"));
+        }
+
+        Ok(())
+    }
+}
+
+/// The contents of an `expected` sidecar file.
+///
+/// The file consists of `key: value` lines, where the keys are:
+///   - `part_1`, `part_2`: the expected answer, compared against the answer's `to_string()`
+///   - `parameters`: extra arguments passed to both parts, as comma-separated Rust expressions
+///   - `part_1_parameters`, `part_2_parameters`: extra arguments for one part only, overriding
+///     `parameters`
+///
+/// Every key may appear at most once, and at least one of `part_1` and `part_2` is required.
+/// Empty lines and lines starting with `#` are ignored.
+#[derive(Default)]
+struct Expected {
+    part_1: Option<String>,
+    part_2: Option<String>,
+    parameters: Option<String>,
+    part_1_parameters: Option<String>,
+    part_2_parameters: Option<String>,
+}
+
+impl Expected {
+    fn parse(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|error| format!("could not read {}: {error}", path.display()))?;
+        let mut expected = Self::default();
+
+        for (number, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line.split_once(':').ok_or_else(|| {
+                format!(
+                    "{}:{}: expected a `key: value` line",
+                    path.display(),
+                    number + 1
+                )
+            })?;
+            let slot = match key.trim() {
+                "part_1" => &mut expected.part_1,
+                "part_2" => &mut expected.part_2,
+                "parameters" => &mut expected.parameters,
+                "part_1_parameters" => &mut expected.part_1_parameters,
+                "part_2_parameters" => &mut expected.part_2_parameters,
+                key => {
+                    return Err(format!(
+                        "{}:{}: unknown key `{key}`",
+                        path.display(),
+                        number + 1
+                    ));
+                }
+            };
+
+            if slot.is_some() {
+                return Err(format!(
+                    "{}:{}: duplicate key `{}`",
+                    path.display(),
+                    number + 1,
+                    key.trim()
+                ));
+            }
+
+            let value = value.trim();
+            if value.is_empty() {
+                return Err(format!(
+                    "{}:{}: empty value for key `{}`",
+                    path.display(),
+                    number + 1,
+                    key.trim()
+                ));
+            }
+
+            *slot = Some(value.to_string());
+        }
+
+        if expected.part_1.is_none() && expected.part_2.is_none() {
+            return Err(format!(
+                "{}: expected a `part_1` or `part_2` answer",
+                path.display()
+            ));
+        }
+
+        Ok(expected)
+    }
+}
+
+fn parse_parameters(parameters: Option<&String>) -> syn::Result<Vec<Expr>> {
+    parameters.map_or(Ok(vec![]), |parameters| {
+        syn::parse::Parser::parse_str(Punctuated::<Expr, Token![,]>::parse_terminated, parameters)
+            .map(|parameters| parameters.into_iter().collect())
+    })
+}
+
+/// Find all `test-input*` files in `dir` that have an `expected` sidecar file, sorted by name.
+fn example_files(dir: &Path) -> Result<Vec<(PathBuf, PathBuf)>, String> {
+    let entries = fs::read_dir(dir)
+        .map_err(|error| format!("could not read directory {}: {error}", dir.display()))?;
+    let mut examples = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name.starts_with("test-input") && !name.ends_with(EXPECTED_SUFFIX)
+                })
+        })
+        .filter_map(|input| {
+            let mut expected = input.clone().into_os_string();
+            expected.push(EXPECTED_SUFFIX);
+            let expected = PathBuf::from(expected);
+
+            expected.is_file().then_some((input, expected))
+        })
+        .collect::<Vec<_>>();
+    examples.sort();
+
+    Ok(examples)
+}
+
+/// Turn a file name like `test-input-2.txt` into an identifier like `test_input_2_txt`.
+fn test_name(input: &Path) -> String {
+    input
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+pub fn expand(args: &Args, mut module: ItemMod) -> syn::Result<ItemMod> {
+    let error = |message: String| syn::Error::new_spanned(&module.ident, message);
+    let Some((_, items)) = module.content.as_mut() else {
+        return Err(syn::Error::new_spanned(
+            &module.ident,
+            "`example_tests` can only be used on inline modules",
+        ));
+    };
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| syn::Error::new_spanned(&module.ident, "CARGO_MANIFEST_DIR is not set"))?;
+    let dir = Path::new(&manifest_dir).join(&args.dir);
+    let examples = example_files(&dir).map_err(&error)?;
+    if examples.is_empty() {
+        return Err(error(format!(
+            "no `test-input*` file with an `{EXPECTED_SUFFIX}` file next to it in {}",
+            dir.display()
+        )));
+    }
+
+    let parse = &args.parse;
+    for (input, expected_path) in examples {
+        let expected = Expected::parse(&expected_path).map_err(&error)?;
+        let name = test_name(&input);
+        let input = input.to_string_lossy().into_owned();
+        let expected_path = expected_path.to_string_lossy().into_owned();
+
+        for (part, function, answer, parameters) in [
+            (
+                "part_1",
+                &args.part_1,
+                &expected.part_1,
+                expected.part_1_parameters.as_ref(),
+            ),
+            (
+                "part_2",
+                &args.part_2,
+                &expected.part_2,
+                expected.part_2_parameters.as_ref(),
+            ),
+        ] {
+            let Some(answer) = answer else {
+                continue;
+            };
+            let parameters = parse_parameters(parameters.or(expected.parameters.as_ref()))?;
+            let test = format_ident!("{name}_{part}");
+
+            items.push(syn::parse_quote! {
+                #[test]
+                fn #test() {
+                    // Makes sure the test is rebuilt whenever the expected answers change.
+                    const _: &str = include_str!(#expected_path);
+
+                    let input = #parse(include_str!(#input).lines());
+                    let result = #function(&input #(, #parameters)*);
+
+                    assert_eq!(result.to_string(), #answer);
+                }
+            });
+        }
+    }
+
+    Ok(module)
+}
@@ -1,6 +1,8 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{self, Data, DeriveInput};
+use syn::{self, Data, DeriveInput, ItemMod};
+
+mod example_tests;
 
 #[proc_macro_derive(EnumVariants)]
 pub fn derive_enum_variants(input: TokenStream) -> TokenStream {
@@ -27,3 +29,48 @@ pub fn derive_enum_variants(input: TokenStream) -> TokenStream {
         todo!()
     }}
 }
+
+/// Generate one test per example input that has an `expected` sidecar file.
+///
+/// Put this on the (inline) tests module of a day. For every `test-input*` file in the crate's
+/// `src` directory with a `<name>.expected` file next to it, a `#[test]` is generated for each
+/// part listed in it. The tests call `parse(input.lines())`, pass the result by reference to
+/// `part_1`/`part_2` (along with any parameters) and compare the answer's `to_string()` to the
+/// expected value.
+///
+/// A sidecar file looks like this:
+///
+/// ```text
+/// part_1: 40
+/// part_2: 25272
+/// # Extra arguments, as Rust expressions
+/// part_1_parameters: 10
+/// ```
+///
+/// The directory and the functions being called can be overridden:
+///
+/// ```ignore
+/// #[cfg(test)]
+/// #[aoc_macros::example_tests(part_1 = part_1_parameterized)]
+/// mod tests {
+///     use super::*;
+/// }
+/// ```
+///
+/// Finding no example with an `expected` file, or an `expected` file without any answer, is a
+/// compile error, so that a day's tests can't pass before its answers are filled in.
+///
+/// *NB:* adding a new example file doesn't trigger a rebuild by itself, so touch the source file
+/// (or run `cargo clean -p <crate>`) after adding one.
+#[proc_macro_attribute]
+pub fn example_tests(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut args = example_tests::Args::default();
+    let args_parser = syn::meta::parser(|meta| args.parse_meta(meta));
+    syn::parse_macro_input!(attr with args_parser);
+    let module = syn::parse_macro_input!(item as ItemMod);
+
+    match example_tests::expand(&args, module) {
+        Ok(module) => quote!(#module).into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
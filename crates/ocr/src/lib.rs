@@ -0,0 +1,242 @@
+//! Recognition of the block letters some puzzles "draw" as their answer.
+//!
+//! Two fonts show up in Advent of Code: a small one that is 6 pixels high (mostly 4 pixels wide),
+//! and a large one that is 10 pixels high (6 pixels wide). Letters are separated by at least one
+//! empty column.
+
+use std::fmt::Display;
+
+use grid::Grid;
+
+const SMALL_FONT: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const LARGE_FONT: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OcrError {
+    /// The image (after trimming empty rows) isn't as high as any of the known fonts.
+    UnsupportedHeight(usize),
+    /// The glyph starting at `column` doesn't match any letter in the font.
+    UnknownGlyph { column: usize, glyph: String },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::UnsupportedHeight(height) => {
+                write!(f, "No font is {height} pixels high")
+            }
+            OcrError::UnknownGlyph { column, glyph } => {
+                write!(f, "Unknown glyph at column {column}:\n{glyph}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// Recognize the letters drawn in `image`, where `true` is a lit pixel.
+///
+/// Empty rows above and below the letters are ignored, so the image may be larger than the text
+/// itself.
+pub fn recognize(image: &Grid<bool>) -> Result<String, OcrError> {
+    let lit = |column: usize, row: usize| *image.get(column, row).unwrap();
+    let non_empty_rows = (0..image.height())
+        .filter(|row| (0..image.width()).any(|column| lit(column, *row)))
+        .collect::<Vec<_>>();
+    let (Some(&top), Some(&bottom)) = (non_empty_rows.first(), non_empty_rows.last()) else {
+        return Ok(String::new());
+    };
+    let height = bottom - top + 1;
+    let font: &[(char, &str)] = match height {
+        6 => &SMALL_FONT,
+        10 => &LARGE_FONT,
+        _ => return Err(OcrError::UnsupportedHeight(height)),
+    };
+
+    let column_is_empty = |column: usize| (top..=bottom).all(|row| !lit(column, row));
+    let mut result = String::new();
+    let mut column = 0;
+
+    while column < image.width() {
+        if column_is_empty(column) {
+            column += 1;
+            continue;
+        }
+
+        let start = column;
+        while column < image.width() && !column_is_empty(column) {
+            column += 1;
+        }
+
+        let glyph = (top..=bottom)
+            .map(|row| {
+                (start..column)
+                    .map(|column| if lit(column, row) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        match font.iter().find(|(_, pattern)| *pattern == glyph) {
+            Some((letter, _)) => result.push(*letter),
+            None => {
+                return Err(OcrError::UnknownGlyph {
+                    column: start,
+                    glyph,
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Recognize the letters drawn in `image`, where `#` is a lit pixel and any other character isn't.
+///
+/// Lines of unequal length are treated as if they were padded with unlit pixels.
+pub fn recognize_str(image: &str) -> Result<String, OcrError> {
+    let width = image
+        .lines()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    recognize(
+        &image
+            .lines()
+            .map(|line| {
+                let mut row = line.chars().map(|c| c == '#').collect::<Vec<_>>();
+                row.resize(width, false);
+                row
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_font() {
+        let image = "\
+#..#.####.#....#.....##..
+#..#.#....#....#....#..#.
+####.###..#....#....#..#.
+#..#.#....#....#....#..#.
+#..#.#....#....#....#..#.
+#..#.####.####.####..##..";
+
+        assert_eq!(recognize_str(image), Ok("HELLO".to_string()));
+    }
+
+    #[test]
+    fn test_large_font() {
+        let image = LARGE_FONT
+            .iter()
+            .map(|(_, pattern)| pattern.lines().collect::<Vec<_>>())
+            .fold(vec![String::new(); 10], |mut rows, glyph| {
+                for (row, line) in rows.iter_mut().zip(glyph) {
+                    row.push_str(line);
+                    row.push_str("..");
+                }
+                rows
+            })
+            .join("\n");
+
+        assert_eq!(recognize_str(&image), Ok("ABCEFGHJKLNPRXZ".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_glyph() {
+        assert_eq!(
+            recognize_str("###\n#.#\n###\n#.#\n#.#\n###\n"),
+            Err(OcrError::UnknownGlyph {
+                column: 0,
+                glyph: "###\n#.#\n###\n#.#\n#.#\n###".to_string()
+            })
+        );
+        assert_eq!(recognize_str("#\n#"), Err(OcrError::UnsupportedHeight(2)));
+    }
+}
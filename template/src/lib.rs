@@ -21,24 +21,7 @@ pub fn part_2(input: &Input) -> Output2 {
 }
 
 #[cfg(test)]
+#[aoc_macros::example_tests]
 mod tests {
     use super::*;
-
-    const INPUT: &str = include_str!("test-input");
-
-    #[test]
-    fn test_part_1() {
-        let input = parse(INPUT.lines());
-        let result = part_1(&input);
-
-        assert_eq!(result, 0);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = parse(INPUT.lines());
-        let result = part_2(&input);
-
-        assert_eq!(result, 0);
-    }
 }
@@ -1,29 +1,8 @@
-use std::{io, str::FromStr};
+use std::io;
 
 use aoc_timing::trace::log_run;
-use rapidhash::{RapidHashMap, RapidHashSet};
-
-#[derive(Debug)]
-enum Direction {
-    Right,
-    Left,
-    Up,
-    Down,
-}
-
-impl FromStr for Direction {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "R" => Ok(Direction::Right),
-            "L" => Ok(Direction::Left),
-            "U" => Ok(Direction::Up),
-            "D" => Ok(Direction::Down),
-            _ => Err("Unknown direction: {s}".into()),
-        }
-    }
-}
+use coord::{Intersection, Point2, Segment};
+use direction::Direction;
 
 #[derive(Debug)]
 struct Step(Direction, i64);
@@ -33,20 +12,13 @@ impl Step {
         let (direction, count) = input.split_at(1);
 
         Step(
-            Direction::from_str(direction).expect("invalid direction"),
+            direction.parse().expect("invalid direction"),
             count.parse().expect("Invalid count"),
         )
     }
 
-    fn points_from(&self, (x, y): (i64, i64)) -> Vec<(i64, i64)> {
-        use Direction::*;
-
-        match self.0 {
-            Left => (x - self.1..=x - 1).rev().map(|x| (x, y)).collect(),
-            Right => (x + 1..=x + self.1).map(|x| (x, y)).collect(),
-            Up => (y - self.1..=y - 1).rev().map(|y| (x, y)).collect(),
-            Down => (y + 1..=y + self.1).map(|y| (x, y)).collect(),
-        }
+    fn segment_from(&self, start: Point2<i64>) -> Segment {
+        Segment::new(start, start + Point2::from(self.0) * self.1)
     }
 }
 
@@ -56,23 +28,55 @@ struct Wire {
 }
 
 impl Wire {
-    fn points(&self) -> Vec<(i64, i64)> {
+    /// The segments of this wire, each with the number of steps it takes to reach its start.
+    fn segments(&self) -> Vec<(Segment, u64)> {
         self.steps
             .iter()
-            .fold(((0, 0), vec![]), |(start, mut points), step| {
-                let step_points = step.points_from(start);
-                let last_point = step_points.last().cloned().unwrap();
-                points.extend(step_points);
+            .scan((Point2::default(), 0), |(start, steps), step| {
+                let segment = step.segment_from(*start);
+                let result = (segment, *steps);
+                *start = segment.end;
+                *steps += segment.steps();
+
+                Some(result)
+            })
+            .collect()
+    }
 
-                (last_point, points)
+    /// All points where this wire crosses `other`, with the steps both wires take to get there.
+    fn crossings(&self, other: &Wire) -> Vec<(Point2<i64>, u64)> {
+        let other_segments = other.segments();
+
+        self.segments()
+            .into_iter()
+            .flat_map(|(a, a_steps)| {
+                other_segments
+                    .iter()
+                    .filter_map(move |&(b, b_steps)| {
+                        let points = match a.intersection(&b)? {
+                            Intersection::Point(point) => vec![point],
+                            Intersection::Segment(overlap) => overlap.lattice_points().collect(),
+                        };
+
+                        Some(points.into_iter().map(move |point| {
+                            let steps = a_steps
+                                + a.start.manhattan_distance(&point)
+                                + b_steps
+                                + b.start.manhattan_distance(&point);
+
+                            (point, steps)
+                        }))
+                    })
+                    .flatten()
             })
-            .1
+            .filter(|(point, _)| *point != Point2::default())
+            .collect()
     }
 }
 
 type Input = Vec<Wire>;
-type Output1 = i64;
-type Output2 = usize;
+type Output1 = u64;
+type Output2 = u64;
 
 fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
     input
@@ -83,44 +87,21 @@ fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
 }
 
 fn part_1(input: &Input) -> Output1 {
-    let mut points_a = input[0].points().into_iter().collect::<RapidHashSet<_>>();
-    let points_b = input[1].points().into_iter().collect::<RapidHashSet<_>>();
-
-    points_a.retain(|point| points_b.contains(point));
-
-    points_a
+    input[0]
+        .crossings(&input[1])
         .into_iter()
-        .map(|(x, y)| x.abs() + y.abs())
+        .map(|(point, _)| point.manhattan_distance(&Point2::default()))
         .min()
         .unwrap()
 }
 
 fn part_2(input: &Input) -> Output2 {
-    let mut points_a = input[0].points().into_iter().enumerate().fold(
-        RapidHashMap::default(),
-        |mut map, (steps, point)| {
-            map.entry(point).or_insert(steps);
-
-            map
-        },
-    );
-    let points_b = input[1].points().into_iter().enumerate().fold(
-        RapidHashMap::default(),
-        |mut map, (steps, point)| {
-            map.entry(point).or_insert(steps);
-
-            map
-        },
-    );
-
-    points_a.retain(|k, _| points_b.contains_key(k));
-
-    points_a
+    input[0]
+        .crossings(&input[1])
         .into_iter()
-        .map(|(point, steps)| steps + points_b[&point])
+        .map(|(_, steps)| steps)
         .min()
         .unwrap()
-        + 2
 }
 
 fn main() {
@@ -1,13 +1,24 @@
 use std::io;
 
 use aoc_timing::trace::log_run;
-use grid::Grid;
+use grid::{Grid, GridView};
 use itertools::Itertools;
 
-type Layer = Grid<u8>;
-
+/// All layers, stacked on top of each other in a single grid.
 struct Image {
-    layers: Vec<Layer>,
+    pixels: Grid<u8>,
+    layer_height: usize,
+}
+
+impl Image {
+    fn layers(&self) -> impl Iterator<Item = GridView<'_, u8>> {
+        (0..self.pixels.height())
+            .step_by(self.layer_height)
+            .map(|row| {
+                self.pixels
+                    .view(0, row, self.pixels.width(), self.layer_height)
+            })
+    }
 }
 
 type Input = Image;
@@ -17,84 +28,53 @@ type Output2 = String;
 fn parse<S: AsRef<str>, I: Iterator<Item = S>>(mut input: I, width: usize, height: usize) -> Input {
     let line = input.next().unwrap();
     Image {
-        layers: line
+        pixels: line
             .as_ref()
             .chars()
             .map(|c| c.to_digit(10).unwrap() as u8)
-            .chunks(width * height)
+            .chunks(width)
             .into_iter()
-            .map(|layer_iter| {
-                layer_iter
-                    .chunks(width)
-                    .into_iter()
-                    .map(|line| line.collect())
-                    .collect()
-            })
+            .map(|row| row.collect())
             .collect(),
+        layer_height: height,
     }
 }
 
 fn part_1(input: &Input) -> Output1 {
     let fewest_zeroes_layer = input
-        .layers
-        .iter()
-        .min_by_key(|layer| {
-            layer
-                .coordinates()
-                .map(|coord| layer.get_coord(coord).unwrap())
-                .filter(|pixel| **pixel == 0)
-                .count()
-        })
+        .layers()
+        .min_by_key(|layer| layer.iter().filter(|pixel| **pixel == 0).count())
         .unwrap();
 
     let pixel_counts = fewest_zeroes_layer
-        .coordinates()
-        .map(|coord| fewest_zeroes_layer.get_coord(coord).unwrap())
+        .iter()
         .into_grouping_map_by(|x| **x)
         .fold(0usize, |acc, _, _| acc + 1);
 
     pixel_counts[&1] * pixel_counts[&2]
 }
 
-fn part_2(input: &Input) -> Output2 {
-    let final_image: Grid<u8> = input.layers[0]
+fn decode_image(input: &Input) -> Grid<bool> {
+    let first_layer = input.layers().next().unwrap();
+
+    first_layer
         .coordinates()
         .map(|coord| {
             input
-                .layers
-                .iter()
-                .map(|layer| layer.get_coord(coord).unwrap())
-                .find(|color| **color != 2)
-                .unwrap_or(&2)
+                .layers()
+                .map(|layer| layer[coord])
+                .find(|color| *color != 2)
+                .unwrap_or(2)
         })
-        .copied()
-        .chunks(input.layers[0].width())
+        .map(|color| color == 1)
+        .chunks(first_layer.width())
         .into_iter()
         .map(|row| row.collect())
-        .collect();
-
-    let mut result = String::new();
-
-    println!(
-        "final image: width={}; height={}",
-        final_image.width(),
-        final_image.height()
-    );
-
-    for row in 0..final_image.height() {
-        for column in 0..final_image.width() {
-            result.push(match final_image.get(column, row).unwrap() {
-                1 => '#',
-                _ => ' ',
-            });
-        }
-
-        if row != final_image.height() - 1 {
-            result.push('\n');
-        }
-    }
+        .collect()
+}
 
-    result
+fn part_2(input: &Input) -> Output2 {
+    ocr::recognize(&decode_image(input)).expect("Image doesn't contain known letters")
 }
 
 fn main() {
@@ -113,7 +93,7 @@ fn main() {
         println!("Part 1: {part_1}");
 
         let part_2 = log_run("Part 2", || part_2(&input));
-        println!("Part 2:\n{part_2}");
+        println!("Part 2: {part_2}");
     });
 }
 
@@ -125,7 +105,7 @@ mod tests {
     fn test_part_1() {
         let input = parse("123456789012".lines(), 3, 2);
 
-        assert_eq!(input.layers.len(), 2);
+        assert_eq!(input.layers().count(), 2);
 
         let result = part_1(&input);
 
@@ -133,10 +113,13 @@ mod tests {
     }
 
     #[test]
-    fn test_part_2() {
+    fn test_decode_image() {
         let input = parse("0222112222120000".lines(), 2, 2);
-        let result = part_2(&input);
+        let result = decode_image(&input);
 
-        assert_eq!(result, " #\n# ");
+        assert_eq!(
+            result,
+            Grid::new(vec![vec![false, true], vec![true, false]]).unwrap()
+        );
     }
 }
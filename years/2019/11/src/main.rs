@@ -1,12 +1,13 @@
-use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::io;
 
 use aoc_timing::trace::log_run;
+use coord::Point2;
+use direction::{Direction, Turtle};
+use grid::SparseGrid;
 use intcode::Computer;
 use intcode::OpCode;
 use intcode::SplitIO;
-use itertools::Itertools;
 
 type Input = Computer;
 type Output1 = usize;
@@ -18,121 +19,43 @@ fn parse<S: AsRef<str>, I: Iterator<Item = S>>(mut input: I) -> Input {
     Computer::parse(line.as_ref())
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    // 0 => left; 1 => right
-    fn turn(&self, left_right: i64) -> Self {
-        let left = left_right == 0;
-
-        match (self, left) {
-            (Direction::Up, true) | (Direction::Down, false) => Direction::Left,
-            (Direction::Up, false) | (Direction::Down, true) => Direction::Right,
-            (Direction::Left, false) | (Direction::Right, true) => Direction::Up,
-            (Direction::Left, true) | (Direction::Right, false) => Direction::Down,
-        }
-    }
-
-    fn step(&self, x: i64, y: i64) -> (i64, i64) {
-        match self {
-            Direction::Up => (x, y - 1),
-            Direction::Down => (x, y + 1),
-            Direction::Left => (x - 1, y),
-            Direction::Right => (x + 1, y),
-        }
-    }
-}
-
-fn part_1(input: &Input) -> Output1 {
-    let mut computer = input.clone();
+/// Run the painting robot, starting on a panel of `start_color`, and return the hull.
+///
+/// Panels that were painted at least once are set in the returned grid, `true` meaning white.
+fn paint(computer: &Computer, start_color: i64) -> SparseGrid<bool> {
+    let mut computer = computer.clone();
     let mut input = VecDeque::new();
     let mut output = VecDeque::new();
-    let mut painted_panels = HashSet::new();
-    let mut white_panels = HashSet::new();
-    let mut direction = Direction::Up;
-    let mut x = 0;
-    let mut y = 0;
+    let mut hull = SparseGrid::new(false);
+    let mut robot = Turtle::new(Point2::new(0, 0), Direction::Up);
 
-    input.push_back(0);
+    input.push_back(start_color);
 
     while let OpCode::Input = computer.run(&mut SplitIO::new(&mut input, &mut output)) {
-        painted_panels.insert((x, y));
-
         let color = output.pop_front().expect("No color?");
 
-        if color == 1 {
-            white_panels.insert((x, y));
-        } else {
-            white_panels.remove(&(x, y));
-        }
-
-        let turn = output.pop_front().expect("No turn?");
+        hull.set(robot.position.x, robot.position.y, color == 1);
 
-        direction = direction.turn(turn);
-        (x, y) = direction.step(x, y);
+        robot = match output.pop_front().expect("No turn?") {
+            0 => robot.turn_left(),
+            _ => robot.turn_right(),
+        }
+        .forward(1);
 
-        input.push_back(if white_panels.contains(&(x, y)) { 1 } else { 0 });
+        input.push_back(i64::from(*hull.get(robot.position.x, robot.position.y)));
     }
 
-    painted_panels.len()
+    hull
 }
 
-fn part_2(input: &Input) -> Output2 {
-    let mut computer = input.clone();
-    let mut input = VecDeque::new();
-    let mut output = VecDeque::new();
-    let mut painted_panels = HashSet::new();
-    let mut white_panels = HashSet::from([(0, 0)]);
-    let mut direction = Direction::Up;
-    let mut x = 0;
-    let mut y = 0;
-
-    input.push_back(1);
-
-    while let OpCode::Input = computer.run(&mut SplitIO::new(&mut input, &mut output)) {
-        painted_panels.insert((x, y));
-
-        let color = output.pop_front().expect("No color?");
-
-        if color == 1 {
-            white_panels.insert((x, y));
-        } else {
-            white_panels.remove(&(x, y));
-        }
-
-        let turn = output.pop_front().expect("No turn?");
-
-        direction = direction.turn(turn);
-        (x, y) = direction.step(x, y);
+fn part_1(input: &Input) -> Output1 {
+    paint(input, 0).len()
+}
 
-        input.push_back(if white_panels.contains(&(x, y)) { 1 } else { 0 });
-    }
+fn part_2(input: &Input) -> Output2 {
+    let (image, _) = paint(input, 1).to_grid();
 
-    let ((min_x, min_y), (max_x, max_y)) = match white_panels.iter().minmax() {
-        itertools::MinMaxResult::NoElements => panic!("No white panels?"),
-        itertools::MinMaxResult::OneElement(_) => panic!("Only a single white panel?"),
-        itertools::MinMaxResult::MinMax(min, max) => (min, max),
-    };
-
-    (*min_y..=*max_y + 1)
-        .map(|y| {
-            (*min_x..=*max_x)
-                .map(|x| {
-                    if white_panels.contains(&(x, y)) {
-                        '#'
-                    } else {
-                        '.'
-                    }
-                })
-                .collect::<String>()
-        })
-        .join("\n")
+    ocr::recognize(&image).expect("Hull doesn't show known letters")
 }
 
 fn main() {
@@ -147,6 +70,6 @@ fn main() {
         println!("Part 1: {part_1}");
 
         let part_2 = log_run("Part 2", || part_2(&input));
-        println!("Part 2:\n{part_2}");
+        println!("Part 2: {part_2}");
     });
 }
@@ -32,7 +32,7 @@ impl Tile {
     }
 
     #[cfg(feature = "tui")]
-    fn to_char(&self) -> char {
+    fn to_char(self) -> char {
         match self {
             Tile::Empty => ' ',
             Tile::Wall => '█',
@@ -78,55 +78,39 @@ fn part_1(input: &Input) -> Output1 {
 
 #[cfg(feature = "tui")]
 mod tui {
-    use std::{
-        thread::sleep,
-        time::{Duration, Instant},
-    };
-
     use super::*;
     use grid::Grid;
-    use ratatui::{
-        DefaultTerminal, Frame,
-        layout::{Alignment, Flex, Layout, Rect},
-        style::{Modifier, Style},
-        text::Text,
-        widgets::{Block, BorderType, Padding, Paragraph},
-    };
+    use grid_tui::{Color, Viewer};
+    use std::time::Duration;
+
+    fn draw_tile(tile: &Tile) -> (char, Color) {
+        let color = match tile {
+            Tile::Empty => Color::Reset,
+            Tile::Wall => Color::Gray,
+            Tile::Block => Color::Yellow,
+            Tile::HorizontalPaddle => Color::Cyan,
+            Tile::Ball => Color::Red,
+        };
+
+        (tile.to_char(), color)
+    }
 
     pub struct TuiState {
-        terminal: DefaultTerminal,
+        viewer: Viewer,
         display: Grid<Tile>,
-        start_time: Instant,
         should_render: bool,
-        delay: Option<u64>,
-    }
-
-    fn create_centered_layout(frame: &Frame) -> Rect {
-        let area = frame.area();
-        let horizontal = Layout::horizontal([42]).flex(Flex::Center);
-        let vertical = Layout::vertical([26]).flex(Flex::Center);
-        let [area] = vertical.areas(area);
-        let [area] = horizontal.areas(area);
-
-        area
     }
 
     impl TuiState {
         pub fn init() -> Self {
-            let terminal = ratatui::init();
             let display: Grid<Tile> =
                 std::iter::repeat_n(std::iter::repeat_n(Tile::Empty, 40).collect_vec(), 30)
                     .collect();
-            let delay = std::env::var("DELAY_MILLIS")
-                .map(|delay| delay.parse().expect("Invalid DELAY_MILLIS value"))
-                .ok();
 
             Self {
-                terminal,
+                viewer: Viewer::init(),
                 display,
-                start_time: Instant::now(),
                 should_render: false,
-                delay,
             }
         }
 
@@ -134,38 +118,8 @@ mod tui {
             self.display.update(x as usize, y as usize, *tile);
 
             if self.should_render && tile != &Tile::Empty {
-                let display_string = (0..=self.display.height())
-                    .map(|row| {
-                        self.display
-                            .row(row)
-                            .map(|tile| tile.to_char())
-                            .collect::<String>()
-                    })
-                    .join("\n");
-
-                self.terminal
-                    .draw(|frame| {
-                        let run_duration = Instant::now().duration_since(self.start_time);
-                        let display_text = Paragraph::new(Text::raw(display_string)).block(
-                            Block::bordered()
-                                .border_type(BorderType::Rounded)
-                                .padding(Padding::symmetric(1, 2))
-                                .title(format!(" [ Score: {score} ] "))
-                                .title_bottom(format!(
-                                    " [ {:02}:{:02} ] ",
-                                    run_duration.as_secs() / 60,
-                                    run_duration.as_secs() % 60
-                                ))
-                                .title_alignment(Alignment::Center),
-                        );
-
-                        frame.render_widget(display_text, create_centered_layout(frame));
-                    })
-                    .unwrap();
-
-                if let Some(delay) = self.delay {
-                    sleep(Duration::from_millis(delay));
-                }
+                self.viewer.set_title(format!("Score: {score}"));
+                self.viewer.show(&self.display, draw_tile);
             }
         }
 
@@ -173,49 +127,13 @@ mod tui {
             self.should_render = true;
         }
 
-        pub fn game_over(&mut self, score: i64) {
-            let total_duration = Instant::now().duration_since(self.start_time);
-
-            self.terminal
-                .draw(|frame| {
-                    let paragraph = Paragraph::new(Text::styled(
-                        r#"
-        _      _                     _ 
- /\   /(_) ___| |_ ___  _ __ _   _  / \
- \ \ / / |/ __| __/ _ \| '__| | | |/  /
-  \ V /| | (__| || (_) | |  | |_| /\_/ 
-   \_/ |_|\___|\__\___/|_|   \__, \/   
-                             |___/     
-                            "#,
-                        Style::default()
-                            .add_modifier(Modifier::BOLD)
-                            .add_modifier(Modifier::SLOW_BLINK),
-                    ))
-                    .block(
-                        Block::bordered()
-                            .border_type(BorderType::Rounded)
-                            .title_top(format!(" [ Score: {score} ] "))
-                            .title_bottom(format!(
-                                " [ {:02}:{:02} ] ",
-                                total_duration.as_secs() / 60,
-                                total_duration.as_secs() % 60
-                            ))
-                            .title_alignment(Alignment::Center),
-                    );
-
-                    let area = create_centered_layout(frame);
-                    let [area] = Layout::vertical([10]).flex(Flex::Center).areas(area);
-                    frame.render_widget(paragraph, area)
-                })
-                .unwrap();
-
-            sleep(Duration::from_secs(5));
-        }
-    }
-
-    impl Drop for TuiState {
-        fn drop(&mut self) {
-            ratatui::restore();
+        pub fn game_over(self, score: i64) {
+            self.viewer.finish(
+                &self.display,
+                draw_tile,
+                &format!("Victory! Score: {score}"),
+                Duration::from_secs(5),
+            );
         }
     }
 }
@@ -1,5 +1,5 @@
 use aoc_timing::trace::log_run;
-use grid::{Grid, LineOfSightNeighbors};
+use grid::{FOUR_WAY_STEPS, Grid};
 use std::io;
 
 trait Day8Grid {
@@ -9,45 +9,22 @@ trait Day8Grid {
 
 impl<T: PartialOrd> Day8Grid for Grid<T> {
     fn is_cell_visible(&self, column: usize, row: usize) -> bool {
-        let LineOfSightNeighbors {
-            left,
-            right,
-            up,
-            down,
-        } = self.get_line_of_sight_neighbors(column, row);
+        let cell_value = &self[(column, row)];
 
-        [up, left, right, down]
-            .into_iter()
-            .any(|potential_blockers| {
-                potential_blockers
-                    .into_iter()
-                    .all(|value| value < self.get(column, row).unwrap())
-            })
+        FOUR_WAY_STEPS.into_iter().any(|step| {
+            self.ray((column, row), step)
+                .all(|(_, value)| value < cell_value)
+        })
     }
 
     fn scenic_score(&self, column: usize, row: usize) -> usize {
-        let cell_value = self.get(column, row).unwrap();
-
-        let LineOfSightNeighbors {
-            mut left,
-            right,
-            mut up,
-            down,
-        } = self.get_line_of_sight_neighbors(column, row);
+        let cell_value = &self[(column, row)];
 
-        left.reverse();
-        up.reverse();
-
-        [up, left, right, down]
+        FOUR_WAY_STEPS
             .into_iter()
-            .map(|neighbors| {
-                let neighbor_count = neighbors.len();
-
-                neighbors
-                    .into_iter()
-                    .enumerate()
-                    .find(|(_, value)| value >= &cell_value)
-                    .map_or(neighbor_count, |(i, _)| i + 1)
+            .map(|step| {
+                self.ray_until((column, row), step, |value| value >= cell_value)
+                    .count()
             })
             .product()
     }
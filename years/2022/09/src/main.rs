@@ -1,5 +1,6 @@
 use aoc_timing::trace::log_run;
 use coord::Coordinate2D;
+use direction::Direction;
 use std::collections::HashSet;
 use std::io;
 use std::num::NonZeroUsize;
@@ -23,14 +24,7 @@ impl Rope {
     }
 
     fn move_in_direction(&mut self, direction: Direction) {
-        let head = self.knots.first_mut().unwrap();
-
-        match direction {
-            Direction::Up => head.y += 1,
-            Direction::Down => head.y -= 1,
-            Direction::Left => head.x -= 1,
-            Direction::Right => head.x += 1,
-        }
+        *self.knots.first_mut().unwrap() += direction;
 
         for knot_index in 1..self.knots.len() {
             let head = self.knots[knot_index - 1];
@@ -52,14 +46,6 @@ impl Rope {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
 #[derive(Debug)]
 struct Move {
     distance: i64,
@@ -102,25 +88,14 @@ impl FromStr for Move {
             .parse()
             .map_err(|e| format!("Couldn't parse count {distance}: {e}"))?;
 
-        match direction {
-            "U" => Ok(Move {
-                distance,
-                direction: Direction::Up,
-            }),
-            "D" => Ok(Move {
-                distance,
-                direction: Direction::Down,
-            }),
-            "L" => Ok(Move {
-                distance,
-                direction: Direction::Left,
-            }),
-            "R" => Ok(Move {
-                distance,
-                direction: Direction::Right,
-            }),
-            _ => Err(format!("Unknown move direction: {direction}")),
-        }
+        let direction = direction
+            .parse()
+            .map_err(|e| format!("Couldn't parse direction: {e}"))?;
+
+        Ok(Move {
+            distance,
+            direction,
+        })
     }
 }
 
@@ -1,4 +1,5 @@
 use aoc_timing::trace::log_run;
+use grid::Grid;
 use std::io;
 use std::iter::once;
 use std::str::FromStr;
@@ -30,19 +31,17 @@ impl FromStr for Instruction {
     }
 }
 
-fn main() {
-    env_logger::init();
+type Input = Vec<Instruction>;
 
-    let instructions: Vec<Instruction> = once(Instruction::Noop)
-        .chain(
-            io::stdin()
-                .lines()
-                .map(|result| result.expect("I/O error"))
-                .map(|line| line.parse().unwrap()),
-        )
-        .collect();
+fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
+    once(Instruction::Noop)
+        .chain(input.map(|line| line.as_ref().parse().unwrap()))
+        .collect()
+}
 
-    let states: Vec<_> = (1..)
+/// The value of the X register during each cycle, numbered from 1.
+fn states(instructions: &Input) -> Vec<(i32, i32)> {
+    (1..)
         .zip(
             instructions
                 .iter()
@@ -61,29 +60,62 @@ fn main() {
                     Some(*x)
                 }),
         )
-        .collect();
-
-    let part_1: i32 = log_run("Part 1", || {
-        states
-            .iter()
-            .take(220)
-            .filter(|(i, _)| *i >= 20 && (i - 20) % 40 == 0)
-            .map(|(i, x)| { *i } * x)
-            .sum()
-    });
-    println!("Part 1: {part_1}");
+        .collect()
+}
 
+fn part_1(states: &[(i32, i32)]) -> i32 {
     states
         .iter()
-        .map(|(n, x)| {
-            if x.abs_diff((n - 1) % 40) <= 1 {
-                '#'
-            } else {
-                '.'
-            }
-        })
+        .take(220)
+        .filter(|(i, _)| *i >= 20 && (i - 20) % 40 == 0)
+        .map(|(i, x)| { *i } * x)
+        .sum()
+}
+
+fn part_2(states: &[(i32, i32)]) -> String {
+    let image: Grid<bool> = states
+        .iter()
+        .take(240)
+        .map(|(n, x)| x.abs_diff((n - 1) % 40) <= 1)
         .collect::<Vec<_>>()
         .chunks(40)
-        .map(|chunk| chunk.iter().collect::<String>())
-        .for_each(|line| println!("{line}"));
+        .map(<[bool]>::to_vec)
+        .collect();
+
+    ocr::recognize(&image).expect("CRT doesn't show known letters")
+}
+
+fn main() {
+    env_logger::init();
+
+    let instructions = parse(io::stdin().lines().map(|result| result.expect("I/O error")));
+    let states = states(&instructions);
+
+    let part_1 = log_run("Part 1", || part_1(&states));
+    println!("Part 1: {part_1}");
+
+    let part_2 = log_run("Part 2", || part_2(&states));
+    println!("Part 2: {part_2}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A program that draws letters, unlike the example in the puzzle.
+    const INPUT: &str = include_str!("test-input");
+
+    #[test]
+    fn test_part_1() {
+        let states = states(&parse(INPUT.lines()));
+
+        assert_eq!(part_1(&states), 11000);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let states = states(&parse(INPUT.lines()));
+
+        assert_eq!(part_2(&states), "RGZEHURK");
+    }
 }
@@ -1,7 +1,7 @@
 use aoc_timing::trace::log_run;
-use std::{collections::HashSet, io, ops::Range, str::FromStr};
+use std::{collections::HashSet, io, str::FromStr};
 
-use coord::Coordinate2D;
+use coord::{Coordinate2D, Diamond, row_coverage};
 
 #[derive(Debug)]
 struct Sensor {
@@ -10,22 +10,8 @@ struct Sensor {
 }
 
 impl Sensor {
-    fn distance_to_beacon(&self) -> u64 {
-        self.own_coord
-            .manhattan_distance(&self.closest_beacon_coord)
-    }
-
-    fn impossible_range_on_row(&self, row: i64) -> Range<i64> {
-        let y_distance_from_row = self.own_coord.y.abs_diff(row);
-        let distance_to_beacon = self.distance_to_beacon();
-
-        if distance_to_beacon >= y_distance_from_row {
-            let diff = (distance_to_beacon - y_distance_from_row) as i64;
-
-            (self.own_coord.x - diff)..(self.own_coord.x + diff + 1)
-        } else {
-            0..0
-        }
+    fn range(&self) -> Diamond {
+        Diamond::through(self.own_coord, self.closest_beacon_coord)
     }
 }
 
@@ -60,26 +46,6 @@ impl FromStr for Sensor {
     }
 }
 
-fn ranges_overlap(left: &Range<i64>, right: &Range<i64>) -> bool {
-    left.contains(&right.start) || left.contains(&right.end)
-}
-
-fn remove_overlaps(mut ranges: Vec<Range<i64>>) -> Vec<Range<i64>> {
-    ranges.sort_by_key(|range| range.start);
-    ranges.iter().fold(vec![], |mut acc, range| {
-        if acc
-            .last()
-            .is_some_and(|last| ranges_overlap(last, range)) {
-            let last = acc.last_mut().unwrap();
-            last.end = last.end.max(range.end);
-        } else {
-            acc.push(range.clone());
-        }
-
-        acc
-    })
-}
-
 fn main() {
     env_logger::init();
     let sensors = io::stdin()
@@ -91,19 +57,14 @@ fn main() {
         .iter()
         .map(|sensor| sensor.closest_beacon_coord)
         .collect();
+    let ranges = sensors.iter().map(Sensor::range).collect::<Vec<_>>();
     let part_1_row = 2_000_000;
 
     let part_1: usize = log_run("Part 1", || {
-        remove_overlaps(
-            sensors
-                .iter()
-                .map(|sensor| sensor.impossible_range_on_row(part_1_row))
-                .filter(|range| !range.is_empty())
-                .collect(),
-        )
-        .iter()
-        .map(|range| range.clone().count())
-        .sum::<usize>()
+        row_coverage(&ranges, part_1_row)
+            .into_iter()
+            .map(|range| range.count())
+            .sum::<usize>()
             - unique_beacons
                 .iter()
                 .filter(|beacon| beacon.y == part_1_row)
@@ -114,24 +75,14 @@ fn main() {
 
     let part_2 = log_run("Part 2", || {
         (0..=4_000_000)
-            .map(|row| {
-                let impossible_in_row = sensors
-                    .iter()
-                    .map(|sensor| {
-                        let mut range = sensor.impossible_range_on_row(row);
-                        range.start = range.start.clamp(0, 4_000_000);
-                        range.end = range.end.clamp(0, 4_000_000);
-
-                        range
-                    })
-                    .filter(|range| !range.is_empty())
-                    .collect::<Vec<_>>();
-
-                remove_overlaps(impossible_in_row)
+            .find_map(|y| {
+                // The beacon is in the only gap between covered columns
+                row_coverage(&ranges, y)
+                    .into_iter()
+                    .map(|range| *range.end() + 1)
+                    .find(|x| (0..=4_000_000).contains(x))
+                    .map(|x| x * 4_000_000 + y)
             })
-            .enumerate()
-            .find(|(_, impossible_in_row)| impossible_in_row.len() > 1)
-            .map(|(y, x)| x[0].end * 4_000_000 + y as i64)
             .unwrap()
     });
 
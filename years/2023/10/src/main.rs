@@ -1,8 +1,8 @@
 use std::{cmp::Ordering, io};
 
 use aoc_timing::trace::log_run;
+use coord::{Point2, Polygon};
 use grid::Grid;
-use itertools::Itertools;
 
 #[derive(Debug)]
 enum Tile {
@@ -123,24 +123,17 @@ fn get_loop(input: &Grid<Tile>) -> Vec<(usize, usize)> {
     .collect()
 }
 
-/// Shoelace formula
+/// Count the cells enclosed by the loop, using every loop cell as a vertex of a polygon.
 ///
-/// While it seems to work now, it's not entirely clear to me why...
-/// I'm subtracting the loop length from the sum part of the shoelace formula, then adding one when
-/// I'm done. I'm probably overlooking something silly, but it works, so meh.
-fn cells_within_loop(grid_loop: &[(usize, usize)]) -> i32 {
-    (grid_loop
-        .iter()
-        .cycle()
-        .take(grid_loop.len() + 1)
-        .map(|(x, y)| (*x as i32, *y as i32))
-        .tuple_windows()
-        .map(|((x1, y1), (x2, y2))| (x1 + x2) * (y2 - y1))
-        .sum::<i32>()
-        .abs()
-        - grid_loop.len() as i32)
-        / 2
-        + 1
+/// The loop cells lie on the polygon's boundary, so the enclosed cells are its interior points.
+fn cells_within_loop(grid_loop: &[(usize, usize)]) -> u64 {
+    Polygon::new(
+        grid_loop
+            .iter()
+            .map(|(x, y)| Point2::new(*x as i64, *y as i64))
+            .collect(),
+    )
+    .interior_points()
 }
 
 fn main() {
@@ -11,14 +11,6 @@ enum Rock {
     None,
 }
 
-#[derive(Debug)]
-enum Direction {
-    North,
-    West,
-    South,
-    East,
-}
-
 fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Grid<Rock> {
     input
         .map(|line| {
@@ -35,76 +27,17 @@ fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Grid<Rock> {
         .collect()
 }
 
-fn tilt_platform(input: &mut Grid<Rock>, direction: Direction) {
-    match direction {
-        Direction::North => {
-            for column in 0..input.width() {
-                for row in 0..input.height() {
-                    let rock = input.get(column, row).unwrap();
-
-                    if rock == &Rock::None
-                        && let Some(first_round_cube_row) = (row + 1..input.height())
-                            .take_while(|subrow| input.get(column, *subrow).unwrap() != &Rock::Cube)
-                            .find(|subrow| input.get(column, *subrow).unwrap() == &Rock::Round)
-                    {
-                        input.update(column, row, Rock::Round);
-                        input.update(column, first_round_cube_row, Rock::None);
-                    }
-                }
-            }
-        }
-        Direction::South => {
-            for column in 0..input.width() {
-                for row in (0..input.height()).rev() {
-                    let rock = input.get(column, row).unwrap();
-
-                    if rock == &Rock::None
-                        && let Some(first_round_cube_row) = (0..row)
-                            .rev()
-                            .take_while(|subrow| input.get(column, *subrow).unwrap() != &Rock::Cube)
-                            .find(|subrow| input.get(column, *subrow).unwrap() == &Rock::Round)
-                    {
-                        input.update(column, row, Rock::Round);
-                        input.update(column, first_round_cube_row, Rock::None);
-                    }
-                }
-            }
-        }
-        Direction::East => {
-            for row in 0..input.height() {
-                for column in (0..input.width()).rev() {
-                    let rock = input.get(column, row).unwrap();
-
-                    if rock == &Rock::None
-                        && let Some(first_round_cube_column) = (0..column)
-                            .rev()
-                            .take_while(|subcolumn| {
-                                input.get(*subcolumn, row).unwrap() != &Rock::Cube
-                            })
-                            .find(|subcolumn| input.get(*subcolumn, row).unwrap() == &Rock::Round)
-                    {
-                        input.update(column, row, Rock::Round);
-                        input.update(first_round_cube_column, row, Rock::None);
-                    }
-                }
-            }
-        }
-        Direction::West => {
-            for row in 0..input.height() {
-                for column in 0..input.width() {
-                    let rock = input.get(column, row).unwrap();
-
-                    if rock == &Rock::None
-                        && let Some(first_round_cube_column) = (column + 1..input.width())
-                            .take_while(|subcolumn| {
-                                input.get(*subcolumn, row).unwrap() != &Rock::Cube
-                            })
-                            .find(|subcolumn| input.get(*subcolumn, row).unwrap() == &Rock::Round)
-                    {
-                        input.update(column, row, Rock::Round);
-                        input.update(first_round_cube_column, row, Rock::None);
-                    }
-                }
+fn tilt_north(input: &mut Grid<Rock>) {
+    for column in 0..input.width() {
+        for row in 0..input.height() {
+            let rock = input.get(column, row).unwrap();
+
+            if rock == &Rock::None
+                && let Some(first_round_cube_row) = (row + 1..input.height())
+                    .take_while(|subrow| input.get(column, *subrow).unwrap() != &Rock::Cube)
+                    .find(|subrow| input.get(column, *subrow).unwrap() == &Rock::Round)
+            {
+                input.swap((column, row), (column, first_round_cube_row));
             }
         }
     }
@@ -140,18 +73,20 @@ fn score(input: &Grid<Rock>) -> usize {
 
 fn part_1(input: &Grid<Rock>) -> usize {
     let mut input = input.clone();
-    tilt_platform(&mut input, Direction::North);
+    tilt_north(&mut input);
 
     info!("Part 1 result\n{}", grid_string(&input));
 
     score(&input)
 }
 
+/// Tilt north, west, south and east in turn.
+///
+/// Rotating the platform clockwise after each tilt brings the next side to the north.
 fn apply_tilt_cycle(input: &mut Grid<Rock>) {
-    use Direction::{East, North, South, West};
-
-    for direction in [North, West, South, East] {
-        tilt_platform(input, direction);
+    for _ in 0..4 {
+        tilt_north(input);
+        *input = input.rotate_cw();
     }
 }
 
@@ -2,59 +2,33 @@ use std::iter::once;
 use std::{io, iter::successors};
 
 use aoc_timing::trace::log_run;
+use coord::Point2;
 use dijkstra::dijkstra;
 use direction::Direction;
 use grid::Grid;
 use itertools::Itertools;
 
 type Input = Grid<u32>;
-type Coord = (usize, usize);
+type Coord = Point2<usize>;
 
 fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
-    input
-        .map(|line| {
-            line.as_ref()
-                .chars()
-                .map(|c| c.to_digit(10).unwrap())
-                .collect_vec()
-        })
-        .collect()
+    Grid::parse_chars(input, |c| c.to_digit(10).ok_or("Not a digit")).unwrap()
 }
 
 #[allow(unused)] // Only used for debugging
 fn print_grid_with_path(input: &Input, path: &[Coord]) {
     println!(
         "{}",
-        (0..input.height())
-            .map(|row| (0..input.width())
-                .map(|column| {
-                    if let Some(path_position) =
-                        path.iter().position(|(x, y)| x == &column && y == &row)
-                    {
-                        if path_position == 0 {
-                            char::from_u32(input.get(column, row).unwrap() + u32::from(b'0')).unwrap()
-                        } else {
-                            char::from(
-                                Direction::determine(
-                                    &path[path_position - 1],
-                                    &path[path_position],
-                                )
-                                .unwrap(),
-                            )
-                        }
-                    } else {
-                        char::from_u32(input.get(column, row).unwrap() + u32::from(b'0')).unwrap()
-                    }
-                })
-                .collect::<String>())
-            .join("\n")
+        input
+            .overlay(|cost| char::from_digit(*cost, 10).unwrap())
+            .path(path.iter().map(|&coord| coord.into()))
     );
 }
 
 fn part_1(input: &Input) -> u32 {
-    let end = (input.width() - 1, input.height() - 1);
+    let end = Point2::new(input.width() - 1, input.height() - 1);
     let path = dijkstra(
-        ((0, 0), Direction::Down, 0),
+        (Point2::new(0, 0), Direction::Down, 0),
         |(coord, _, _)| coord == &end,
         |(coord, direction, count)| {
             [
@@ -72,10 +46,10 @@ fn part_1(input: &Input) -> u32 {
             ]
             .into_iter()
             .flatten()
-            .filter(|((next_column, next_row), _, next_count)| {
-                input.is_valid_coord(*next_column, *next_row) && next_count <= &3
+            .filter(|(next_coord, _, next_count)| {
+                input.get_point(*next_coord).is_some() && next_count <= &3
             })
-            .map(|node @ ((column, row), _, _)| (node, *input.get(column, row).unwrap()))
+            .map(|node @ (coord, _, _)| (node, input[coord]))
         },
     )
     .unwrap()
@@ -85,15 +59,12 @@ fn part_1(input: &Input) -> u32 {
 
     // print_grid_with_path(input, &path);
 
-    path.into_iter()
-        .skip(1)
-        .map(|(column, row)| *input.get(column, row).unwrap())
-        .sum()
+    path.into_iter().skip(1).map(|coord| input[coord]).sum()
 }
 
 fn intermediate_coords(from: &Coord, to: &Coord) -> Vec<Coord> {
     if let Some(direction) = Direction::determine(from, to) {
-        let distance = from.0.abs_diff(to.0) + from.1.abs_diff(to.1);
+        let distance = from.manhattan_distance(to);
         successors(Some(*from), |coord| direction.advance(coord, 1))
             .take(distance + 1)
             .skip(1)
@@ -104,11 +75,11 @@ fn intermediate_coords(from: &Coord, to: &Coord) -> Vec<Coord> {
 }
 
 fn part_2(input: &Input) -> u32 {
-    let end = (input.width() - 1, input.height() - 1);
-    let path = once((0, 0))
+    let end = Point2::new(input.width() - 1, input.height() - 1);
+    let path = once(Point2::new(0, 0))
         .chain(
             dijkstra(
-                ((0, 0), Direction::Down, 0),
+                (Point2::new(0, 0), Direction::Down, 0),
                 |(coord, _, _)| coord == &end,
                 |(coord, direction, count)| {
                     [
@@ -127,16 +98,12 @@ fn part_2(input: &Input) -> u32 {
                     .into_iter()
                     .flatten()
                     .filter(|(coords, _, next_count)| {
-                        input.is_valid_coord(coords.last().unwrap().0, coords.last().unwrap().1)
-                            && next_count <= &10
+                        input.get_point(*coords.last().unwrap()).is_some() && next_count <= &10
                     })
                     .map(|(coords, direction, count)| {
                         (
                             (*coords.last().unwrap(), direction, count),
-                            coords
-                                .into_iter()
-                                .map(|(column, row)| *input.get(column, row).unwrap())
-                                .sum::<u32>(),
+                            coords.into_iter().map(|coord| input[coord]).sum::<u32>(),
                         )
                     })
                 },
@@ -151,10 +118,7 @@ fn part_2(input: &Input) -> u32 {
 
     // print_grid_with_path(input, &path);
 
-    path.into_iter()
-        .skip(1)
-        .map(|(column, row)| *input.get(column, row).unwrap())
-        .sum()
+    path.into_iter().skip(1).map(|coord| input[coord]).sum()
 }
 
 fn main() {
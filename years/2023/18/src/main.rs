@@ -1,15 +1,8 @@
 use std::io;
 
 use aoc_timing::trace::log_run;
-use itertools::Itertools;
-
-#[derive(PartialEq)]
-enum Direction {
-    Left,
-    Right,
-    Up,
-    Down,
-}
+use coord::{Point2, Polygon};
+use direction::Direction;
 
 struct DigInstruction {
     direction: Direction,
@@ -26,13 +19,7 @@ fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
             let split = line.split(' ').collect::<Vec<_>>();
             let (direction, distance, color) = (split[0], split[1], split[2]);
 
-            let direction = match direction {
-                "R" => Direction::Right,
-                "L" => Direction::Left,
-                "U" => Direction::Up,
-                "D" => Direction::Down,
-                _ => panic!("Invalid direction {direction}"),
-            };
+            let direction = direction.parse().expect("Invalid direction");
 
             let length: i64 = distance.parse().expect("Invalid length");
 
@@ -47,49 +34,20 @@ fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
         .collect::<Input>()
 }
 
-fn dug_out_circumference(polygon: &[(i64, i64)]) -> i64 {
-    polygon
-        .iter()
-        .copied()
-        .tuple_windows()
-        .map(|((x1, y1), (x2, y2))| x1.abs_diff(x2) as i64 + y1.abs_diff(y2) as i64)
-        .sum()
-}
+/// The dug out area *including* the trench along the edges.
+fn dug_out_area(input: &Input) -> i64 {
+    let polygon = Polygon::from_moves(
+        Point2::new(0, 0),
+        input
+            .iter()
+            .map(|instruction| (instruction.direction, instruction.distance)),
+    );
 
-/// Shoelace formula
-///
-/// We want the area *including* the circumference.
-fn dug_out_area(polygon: &[(i64, i64)]) -> i64 {
-    i64::midpoint(polygon
-        .iter()
-        .copied()
-        .tuple_windows()
-        .map(|((x1, y1), (x2, y2))| (x1 + x2) * (y2 - y1))
-        .sum::<i64>()
-        .abs(), dug_out_circumference(polygon))
-        + 1
-}
-
-fn make_polygon(input: &Input) -> Vec<(i64, i64)> {
-    input
-        .iter()
-        .fold(vec![(0i64, 0i64)], |mut result, instruction| {
-            let (last_x, last_y) = result.last().copied().unwrap();
-            let delta = instruction.distance;
-
-            result.push(match instruction.direction {
-                Direction::Left => (last_x - delta, last_y),
-                Direction::Right => (last_x + delta, last_y),
-                Direction::Down => (last_x, last_y - delta),
-                Direction::Up => (last_x, last_y + delta),
-            });
-
-            result
-        })
+    polygon.enclosed_points() as i64
 }
 
 fn part_1(input: &Input) -> i64 {
-    dug_out_area(&make_polygon(input))
+    dug_out_area(input)
 }
 
 fn part_2(input: &Input) -> i64 {
@@ -98,13 +56,7 @@ fn part_2(input: &Input) -> i64 {
         .map(|instruction| {
             let (distance, direction) = &instruction.color.split_at(5);
             let distance = i64::from_str_radix(distance, 16).unwrap();
-            let direction = match *direction {
-                "0" => Direction::Right,
-                "1" => Direction::Down,
-                "2" => Direction::Left,
-                "3" => Direction::Up,
-                _ => panic!("Unknown encoded direction: {direction}"),
-            };
+            let direction = direction.parse().expect("Unknown encoded direction");
 
             DigInstruction {
                 direction,
@@ -114,7 +66,7 @@ fn part_2(input: &Input) -> i64 {
         })
         .collect::<Vec<_>>();
 
-    dug_out_area(&make_polygon(&input))
+    dug_out_area(&input)
 }
 
 fn main() {
@@ -2,11 +2,11 @@ use std::collections::HashSet;
 use std::io;
 
 use aoc_timing::trace::log_run;
-use grid::Grid;
+use grid::{CellularAutomaton, Grid, Neighborhood};
 
 type Coord = (usize, usize);
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 enum Cell {
     GardenPlot,
     Rock,
@@ -50,27 +50,64 @@ fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
     }
 }
 
-fn step(map: &Grid<Cell>, positions: HashSet<Coord>) -> HashSet<Coord> {
-    positions
-        .into_iter()
-        .flat_map(|(column, row)| {
-            map.get_neighbors(column, row)
-                .into_iter()
-                .filter(|(column, row)| map.get(*column, *row).unwrap() != &Cell::Rock)
-        })
-        .collect()
+fn part_1(input: &Input, steps: usize) -> Output1 {
+    let mut reached = input.map.map(|cell| (*cell, false));
+    reached[input.starting_position].1 = true;
+
+    let mut walk = CellularAutomaton::new(
+        reached,
+        Neighborhood::VonNeumann(1),
+        |(cell, _), neighbors| {
+            (
+                *cell,
+                *cell == Cell::GardenPlot && neighbors.iter().any(|(_, reached)| *reached),
+            )
+        },
+    );
+    walk.run(steps);
+
+    walk.grid().iter().filter(|(_, reached)| *reached).count()
 }
 
-fn part_1(input: &Input, steps: usize) -> Output1 {
-    (0..steps)
-        .fold(HashSet::from([input.starting_position]), |positions, _| {
-            step(&input.map, positions)
-        })
-        .len()
+/// Count the garden plots reachable in exactly `steps` steps on the infinitely tiled map.
+///
+/// A plot reachable in `n` steps is also reachable in `n + 2` steps (by stepping back and forth),
+/// so this counts all plots at most `steps` away with the same parity as `steps`.
+fn reachable_on_tiled_map(input: &Input, steps: usize) -> usize {
+    let map = input.map.tiled();
+    let start = (
+        input.starting_position.0 as i64,
+        input.starting_position.1 as i64,
+    );
+    let mut seen = HashSet::from([start]);
+    let mut frontier = vec![start];
+    let mut reachable = usize::from(steps.is_multiple_of(2));
+
+    for step in 1..=steps {
+        frontier = frontier
+            .into_iter()
+            .flat_map(|(x, y)| map.get_neighbors_iter(x, y))
+            .filter(|(x, y)| map.get(*x, *y) != Some(&Cell::Rock) && seen.insert((*x, *y)))
+            .collect();
+
+        if step % 2 == steps % 2 {
+            reachable += frontier.len();
+        }
+    }
+
+    reachable
 }
 
-fn part_2(input: &Input) -> Output2 {
-    todo!()
+/// Relies on a property of the actual inputs: the starting position's row and column, and the
+/// map's edges are free of rocks. The number of reachable plots then grows quadratically with
+/// every map width walked, so three samples suffice to extrapolate to the required step count.
+fn part_2(input: &Input, steps: usize) -> Output2 {
+    let size = input.map.width();
+    let remainder = steps % size;
+    let [a, b, c] = [0, 1, 2].map(|n| reachable_on_tiled_map(input, remainder + n * size));
+    let n = steps / size;
+
+    a + n * (b - a) + n * (n - 1) / 2 * (c + a - 2 * b)
 }
 
 fn main() {
@@ -84,7 +121,7 @@ fn main() {
         let part_1 = log_run("Part 1", || part_1(&input, 64));
         println!("Part 1: {part_1}");
 
-        let part_2 = log_run("Part 2", || part_2(&input));
+        let part_2 = log_run("Part 2", || part_2(&input, 26_501_365));
         println!("Part 2: {part_2}");
     });
 }
@@ -102,4 +139,25 @@ mod tests {
 
         assert_eq!(result, 16);
     }
+
+    #[test]
+    fn test_reachable_on_tiled_map() {
+        let input = parse(INPUT.lines());
+
+        assert_eq!(reachable_on_tiled_map(&input, 6), 16);
+        assert_eq!(reachable_on_tiled_map(&input, 10), 50);
+        assert_eq!(reachable_on_tiled_map(&input, 50), 1594);
+        assert_eq!(reachable_on_tiled_map(&input, 100), 6536);
+        assert_eq!(reachable_on_tiled_map(&input, 500), 167_004);
+    }
+
+    #[test]
+    fn test_part_2() {
+        // Without rocks, the reachable plots form a diamond of `(steps + 1)²` plots
+        let mut empty_map = vec![".".repeat(11); 11];
+        empty_map[5].replace_range(5..6, "S");
+        let input = parse(empty_map.iter());
+
+        assert_eq!(part_2(&input, 5 + 11 * 4), 50 * 50);
+    }
 }
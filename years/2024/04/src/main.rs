@@ -1,6 +1,7 @@
 use std::io;
 
 use aoc_timing::trace::log_run;
+use direction::Direction8;
 use grid::Grid;
 
 type Input = Grid<char>;
@@ -19,79 +20,49 @@ fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
 }
 
 const XMAS: &str = "XMAS";
-const SAMX: &str = "SAMX";
 
 fn part_1(input: &Input) -> usize {
-    let mut count = 0;
-
-    for row in 0..input.height() {
-        let row = input.row(row).collect::<String>();
-        for x in 0..=row.len() - 4 {
-            let maybe_xmas = &row[x..x + 4];
-            if maybe_xmas == XMAS || maybe_xmas == SAMX {
-                count += 1;
-            }
-        }
-    }
-
-    for column in 0..input.width() {
-        let column = input.column(column).collect::<String>();
-        for x in 0..=column.len() - 4 {
-            let maybe_xmas = &column[x..x + 4];
-            if maybe_xmas == XMAS || maybe_xmas == SAMX {
-                count += 1;
-            }
-        }
-    }
-
-    // Down & to the right
-    for y in 0..input.height() - 3 {
-        for x in 0..input.width() - 3 {
-            let maybe_xmas: String = [(x, y), (x + 1, y + 1), (x + 2, y + 2), (x + 3, y + 3)]
-                .into_iter()
-                .map(|(x, y)| input.get(x, y).unwrap())
-                .collect();
-
-            if maybe_xmas == XMAS || maybe_xmas == SAMX {
-                count += 1;
-            }
-        }
-    }
+    input
+        .coordinates()
+        .filter(|&coord| input[coord] == 'X')
+        .map(|coord| {
+            input
+                .rays(coord)
+                .filter(|ray| {
+                    ray.clone()
+                        .map(|(_, c)| *c)
+                        .take(XMAS.len() - 1)
+                        .eq(XMAS.chars().skip(1))
+                })
+                .count()
+        })
+        .sum()
+}
 
-    // Down & to the left
-    for y in 0..input.height() - 3 {
-        for x in (3..input.width()).rev() {
-            let maybe_xmas: String = [(x, y), (x - 1, y + 1), (x - 2, y + 2), (x - 3, y + 3)]
-                .into_iter()
-                .map(|(x, y)| input.get(x, y).unwrap())
-                .collect();
+fn part_2(input: &Input) -> usize {
+    // Both diagonals through an 'A' need an 'M' on one end and an 'S' on the other
+    let diagonal_ends = |(x, y): (usize, usize), direction: Direction8| {
+        let end = |direction: Direction8| {
+            let (dx, dy) = direction.delta();
 
-            if maybe_xmas == XMAS || maybe_xmas == SAMX {
-                count += 1;
-            }
-        }
-    }
+            input.get(x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)
+        };
 
-    count
-}
+        (end(direction), end(direction.opposite()))
+    };
 
-fn part_2(input: &Input) -> usize {
     input
         .coordinates()
-        .filter(|(x, y)| {
-            (1..input.width() - 1).contains(x)
-                && (1..input.height() - 1).contains(y)
-                && input.get(*x, *y).unwrap() == &'A'
-        })
-        .filter(|(x, y)| {
-            ((input.get(*x - 1, *y - 1).unwrap() == &'M'
-                && input.get(x + 1, y + 1).unwrap() == &'S')
-                || (input.get(*x - 1, *y - 1).unwrap() == &'S'
-                    && input.get(x + 1, y + 1).unwrap() == &'M'))
-                && ((input.get(*x - 1, *y + 1).unwrap() == &'M'
-                    && input.get(x + 1, y - 1).unwrap() == &'S')
-                    || (input.get(*x - 1, *y + 1).unwrap() == &'S'
-                        && input.get(x + 1, y - 1).unwrap() == &'M'))
+        .filter(|&coord| input[coord] == 'A')
+        .filter(|&coord| {
+            [Direction8::UpLeft, Direction8::UpRight]
+                .into_iter()
+                .all(|direction| {
+                    matches!(
+                        diagonal_ends(coord, direction),
+                        (Some('M'), Some('S')) | (Some('S'), Some('M'))
+                    )
+                })
         })
         .count()
 }
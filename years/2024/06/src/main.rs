@@ -3,73 +3,29 @@ use std::io;
 use std::iter::successors;
 
 use aoc_timing::trace::log_run;
+use coord::Point2;
+use direction::{Direction, Turtle};
 use grid::Grid;
 use rayon::prelude::*;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Direction {
-    Up,
-    Left,
-    Down,
-    Right,
-}
-
-impl Direction {
-    fn next_position(&self, position: Coordinates, lab: &Lab) -> Option<Coordinates> {
-        match *self {
-            Direction::Up if position.1 > 0 => Some((position.0, position.1 - 1)),
-            Direction::Left if position.0 > 0 => Some((position.0 - 1, position.1)),
-            Direction::Down if position.1 < lab.height() - 1 => Some((position.0, position.1 + 1)),
-            Direction::Right if position.0 < lab.width() - 1 => Some((position.0 + 1, position.1)),
-            _ => None,
-        }
-    }
-
-    fn turn(&self) -> Direction {
-        match *self {
-            Direction::Up => Direction::Right,
-            Direction::Left => Direction::Up,
-            Direction::Down => Direction::Left,
-            Direction::Right => Direction::Down,
-        }
-    }
-}
-
 #[derive(Debug, PartialEq, Clone, Copy)]
 enum LabCell {
     Empty,
     Obstruction,
 }
 
-type Coordinates = (usize, usize);
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct GuardPosition {
-    coordinates: Coordinates,
-    direction: Direction,
-}
-
+type Coordinates = Point2<i64>;
+type GuardPosition = Turtle<i64>;
 type Lab = Grid<LabCell>;
 
-impl GuardPosition {
-    fn next(&self, lab: &Lab) -> Option<Self> {
-        successors(Some(self.direction), |dir| Some(dir.turn()))
-            .take(4)
-            .map(|dir| {
-                dir.next_position(self.coordinates, lab)
-                    .map(|pos| (dir, pos))
-            })
-            .find(|maybe_next| match maybe_next {
-                None => true, // Guard got out of the lab
-                Some((_, pos)) if *lab.get(pos.0, pos.1).unwrap() != LabCell::Obstruction => true,
-                _ => false,
-            })
-            .flatten()
-            .map(|(dir, pos)| Self {
-                coordinates: pos,
-                direction: dir,
-            })
-    }
+/// Where the guard goes next, turning right in front of obstructions, or `None` once the guard
+/// leaves the lab.
+fn next_guard_position(guard: &GuardPosition, lab: &Lab) -> Option<GuardPosition> {
+    successors(Some(*guard), |guard| Some(guard.turn_right()))
+        .take(4)
+        .find(|guard| lab.get_point(guard.ahead()) != Some(&LabCell::Obstruction))
+        .filter(|guard| lab.get_point(guard.ahead()).is_some())
+        .map(|guard| guard.forward(1))
 }
 
 #[derive(Debug)]
@@ -83,16 +39,15 @@ fn all_guard_positions(
     guard_start_position: GuardPosition,
     lab: &Lab,
 ) -> impl Iterator<Item = GuardPosition> + use<'_> {
-    successors(Some(guard_start_position), |guard_pos| guard_pos.next(lab))
+    successors(Some(guard_start_position), |guard_pos| {
+        next_guard_position(guard_pos, lab)
+    })
 }
 
 type Output = usize;
 
 fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
-    let mut start_position = GuardPosition {
-        coordinates: (0, 0),
-        direction: Direction::Up,
-    };
+    let mut start_position = GuardPosition::new(Point2::new(0, 0), Direction::Up);
 
     let grid = Grid::new(
         input
@@ -106,10 +61,10 @@ fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
                         '.' => LabCell::Empty,
                         '#' => LabCell::Obstruction,
                         '^' => {
-                            start_position = GuardPosition {
-                                coordinates: (column, row),
-                                direction: Direction::Up,
-                            };
+                            start_position = GuardPosition::new(
+                                Point2::from_grid_coord((column, row)).unwrap(),
+                                Direction::Up,
+                            );
                             LabCell::Empty
                         }
                         _ => panic!("Unsupported character found in input: {c}"),
@@ -122,8 +77,8 @@ fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
 
     Input {
         guard_start_position: start_position,
-        guard_positions: successors(Some(start_position), |guard_pos| guard_pos.next(&grid))
-            .map(|pos| pos.coordinates)
+        guard_positions: all_guard_positions(start_position, &grid)
+            .map(|pos| pos.position)
             .collect::<HashSet<_>>(),
         lab: grid,
     }
@@ -141,9 +96,10 @@ fn part_2(input: &Input) -> Output {
         .guard_positions
         .par_iter()
         .copied()
-        .filter(|coord| coord != &input.guard_start_position.coordinates)
-        .map(|(col, row)| {
+        .filter(|coord| coord != &input.guard_start_position.position)
+        .map(|coord| {
             let mut lab = input.lab.clone();
+            let (col, row) = coord.to_grid_coord().unwrap();
 
             lab.update(col, row, LabCell::Obstruction);
 
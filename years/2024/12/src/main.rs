@@ -1,229 +1,29 @@
-use std::collections::BTreeSet;
 use std::io;
 
 use aoc_timing::trace::log_run;
-use fxhash::FxHashMap;
-use fxhash::FxHashSet;
-use grid::Grid;
-use rayon::prelude::*;
+use grid::{Connectivity, Grid, Region};
 
-type Coord = (usize, usize);
-type Input = FxHashMap<char, Vec<FxHashSet<Coord>>>;
+type Input = Vec<Region>;
 type Output = usize;
 
 fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
-    let grid = Grid::new(
-        input
-            .map(|line| {
-                let line = line.as_ref();
-
-                line.chars().collect()
-            })
-            .collect(),
-    )
-    .unwrap();
-
-    flood_group(&grid)
-}
-
-/// Do a flood fill for all cells in the grid.
-///
-/// Basically, pick any cell, flood to find all other cells part of that region, and remove them
-/// all from the grid. Continue until the grid is empty.
-fn flood_group(grid: &Grid<char>) -> FxHashMap<char, Vec<FxHashSet<Coord>>> {
-    let mut cells: BTreeSet<_> = grid
-        .coordinates()
-        .map(|coord @ (column, row)| (grid.get(column, row).unwrap(), coord))
-        .collect();
-    let mut result: FxHashMap<char, Vec<FxHashSet<Coord>>> =
-        FxHashMap::with_hasher(Default::default());
-
-    while let Some(current) = cells.pop_first() {
-        let plant = current.0;
-        let mut region = FxHashSet::with_hasher(Default::default());
-        let mut queue = vec![current];
-
-        while let Some(cell) = queue.pop() {
-            if cell.0 == plant {
-                cells.remove(&cell);
-                region.insert(cell.1);
-
-                queue.extend(
-                    orthogonal_neighbors(&cell.1)
-                        .into_iter()
-                        .filter(|other| !region.contains(other))
-                        .filter_map(|other| {
-                            grid.get(other.0, other.1)
-                                .map(|other_plant| (other_plant, other))
-                        }),
-                );
-            }
-        }
-
-        match result.get_mut(plant) { Some(regions) => {
-            regions.push(region);
-        } _ => {
-            result.insert(*plant, vec![region]);
-        }}
-    }
-
-    result
-}
-
-fn orthogonal_neighbors(cell: &Coord) -> Vec<Coord> {
-    let mut result = Vec::with_capacity(4);
-    result.push((cell.0 + 1, cell.1));
-    result.push((cell.0, cell.1 + 1));
-    if cell.0 > 0 {
-        result.push((cell.0 - 1, cell.1));
-    }
-    if cell.1 > 0 {
-        result.push((cell.0, cell.1 - 1));
-    }
-
-    result
-}
-
-/// Basically, the perimeter of a cell is 4 minus the count of neighboring cells in the same
-/// region.
-fn region_perimeter(region: &FxHashSet<Coord>) -> usize {
-    region
-        .iter()
-        .map(|cell| {
-            4 - region
-                .iter()
-                .filter(|other| {
-                    (other.0.abs_diff(cell.0) == 1 && other.1 == cell.1)
-                        || (other.0 == cell.0 && other.1.abs_diff(cell.1) == 1)
-                })
-                .count()
-        })
-        .sum()
-}
-
-/// The amount of sides for a region is equal to the amount of corners it has, so find the amount
-/// of corners the region has.
-///
-/// This is probably a whole lot uglier than it has to be, but it comes down to the following,
-/// where we're trying to figure out if there's a corner at the north-west of the cell in the
-/// south-east (same letters indicate they're in the same region):
-/// - ```
-///   yy
-///   yX
-///   ```
-///   ✅
-/// - ```
-///   xy
-///   yX
-///   ```
-///   ✅
-/// - ```
-///   xy
-///   yX
-///   ```
-///   ✅
-/// - ```
-///   xx
-///   xX
-///   ```
-///   ❌
-/// - ```
-///   xx
-///   yX
-///   ```
-///   ❌
-/// - ```
-///   yy
-///   xX
-///   ```
-///   ❌
-/// - ```
-///   xy
-///   xX
-///   ```
-///   ❌
-/// - ```
-///   yx
-///   yX
-///   ```
-///   ❌
-///
-/// In a nutshell: either all three of north, north-west and west are not part of the same region,
-/// or north and west are part of the same region and north-west isn't, or north-west is part of
-/// the
-fn region_sides(region: &FxHashSet<Coord>) -> usize {
-    region
-        .par_iter()
-        .map(|cell| {
-            let (north, north_east, north_west) = if cell.1 > 0 {
-                (
-                    region.contains(&(cell.0, cell.1 - 1)),
-                    region.contains(&(cell.0 + 1, cell.1 - 1)),
-                    if cell.0 > 0 {
-                        region.contains(&(cell.0 - 1, cell.1 - 1))
-                    } else {
-                        false
-                    },
-                )
-            } else {
-                (false, false, false)
-            };
-
-            let south_west = if cell.0 > 0 {
-                region.contains(&(cell.0 - 1, cell.1 + 1))
-            } else {
-                false
-            };
-            let east = region.contains(&(cell.0 + 1, cell.1));
-            let south_east = region.contains(&(cell.0 + 1, cell.1 + 1));
-            let south = region.contains(&(cell.0, cell.1 + 1));
-            let west = cell
-                .0
-                .checked_sub(1)
-                .is_some_and(|column| region.contains(&(column, cell.1)));
-
-            let mut corners = 0;
-
-            if (north == west) && (!north_west || !north) {
-                corners += 1;
-            }
-
-            if (north == east) && (!north_east || !north) {
-                corners += 1;
-            }
-
-            if (south == east) && (!south_east || !south) {
-                corners += 1;
-            }
-
-            if (south == west) && (!south_west || !south) {
-                corners += 1;
-            }
-
-            corners
-        })
-        .sum()
+    Grid::parse_chars(input, Ok::<_, ()>)
+        .unwrap()
+        .components(Connectivity::Four)
+        .regions
 }
 
 fn part_1(input: &Input) -> Output {
     input
-        .par_iter()
-        .flat_map(|(_, regions)| {
-            regions
-                .par_iter()
-                .map(|region| region.len() * region_perimeter(region))
-        })
+        .iter()
+        .map(|region| region.area() * region.perimeter)
         .sum()
 }
 
 fn part_2(input: &Input) -> Output {
     input
-        .par_iter()
-        .flat_map(|(_, regions)| {
-            regions
-                .par_iter()
-                .map(|region| region.len() * region_sides(region))
-        })
+        .iter()
+        .map(|region| region.area() * region.sides())
         .sum()
 }
 
@@ -1,7 +1,7 @@
 use std::io;
 
 use aoc_timing::trace::log_run;
-use itertools::Itertools;
+use grid::Grid;
 use log::info;
 use log::log_enabled;
 
@@ -73,23 +73,33 @@ fn print_image(input: &Input) {
     if log_enabled!(log::Level::Info) {
         info!(
             "\n{}",
-            (0..HEIGHT)
-                .map(|y| {
-                    (0..WIDTH)
-                        .map(|x| {
-                            if input.iter().any(|robot| robot.x == x && robot.y == y) {
-                                '#'
-                            } else {
-                                ' '
-                            }
-                        })
-                        .collect::<String>()
-                })
-                .join("\n")
+            Grid::<()>::with_size(WIDTH as usize, HEIGHT as usize)
+                .overlay(|_| ' ')
+                .highlight(
+                    input
+                        .iter()
+                        .map(|robot| (robot.x as usize, robot.y as usize)),
+                    '#'
+                )
         );
     }
 }
 
+/// Save the robots as `tree.png`, which is easier to look at than the logged image.
+#[cfg(feature = "export")]
+fn export_image(input: &Input) {
+    let mut grid = Grid::<bool>::with_size(WIDTH as usize, HEIGHT as usize);
+
+    for robot in input {
+        grid[(robot.x as usize, robot.y as usize)] = true;
+    }
+
+    let color = |robot: &bool| if *robot { [0, 160, 0] } else { [0, 0, 0] };
+
+    grid.save_image("tree.png", 4, color)
+        .expect("Couldn't save tree.png");
+}
+
 fn part_2(input: &Input) -> Output {
     let mut input = input.clone();
 
@@ -101,6 +111,8 @@ fn part_2(input: &Input) -> Output {
             input.iter().filter(|Robot { y: ry, .. }| *ry == y).count() as isize >= WIDTH / 4
         }) {
             print_image(&input);
+            #[cfg(feature = "export")]
+            export_image(&input);
             return seconds as usize;
         }
 
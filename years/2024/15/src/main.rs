@@ -3,6 +3,7 @@ use std::collections::VecDeque;
 use std::io;
 
 use aoc_timing::trace::log_run;
+use direction::Direction;
 use grid::Grid;
 use indexmap::IndexSet;
 use itertools::Itertools;
@@ -17,14 +18,6 @@ enum Cell {
     BoxRight,
 }
 
-#[derive(Clone, Copy, Debug)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
 type Coord = (usize, usize);
 struct Input {
     map: Grid<Cell>,
@@ -80,13 +73,7 @@ fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
                     Map(rows, robot_position)
                 }
                 Movements(rows, robot_position, mut movements) => {
-                    movements.extend(line.chars().map(|c| match c {
-                        '^' => Direction::Up,
-                        'v' => Direction::Down,
-                        '<' => Direction::Left,
-                        '>' => Direction::Right,
-                        _ => panic!("Unknown direction: {c}"),
-                    }));
+                    movements.extend(Direction::parse_moves(line).expect("Invalid movements"));
                     Movements(rows, robot_position, movements)
                 }
             }
@@ -176,9 +163,9 @@ fn do_the_shifting(
                 let from = (delta_back.0(to_shift.0), delta_back.1(to_shift.1));
 
                 if all_shifting.contains(&from) {
-                    map.update(to_shift.0, to_shift.1, *map.get(from.0, from.1).unwrap());
+                    map[*to_shift] = map[from];
                 } else {
-                    map.update(to_shift.0, to_shift.1, Cell::Empty);
+                    map[*to_shift] = Cell::Empty;
                 }
             }
 
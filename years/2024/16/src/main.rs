@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::io;
 
 use aoc_timing::trace::log_run;
+use coord::Point2;
 use direction::Direction;
 use grid::Grid;
 use itertools::Itertools;
@@ -13,7 +14,7 @@ enum Tile {
     End,
 }
 
-type Coord = (usize, usize);
+type Coord = Point2<usize>;
 type Input = (usize, HashSet<Coord>);
 
 type Output = usize;
@@ -31,11 +32,11 @@ fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
                         '#' => Tile::Wall,
                         '.' => Tile::Empty,
                         'E' => {
-                            end_position = Some((column, row));
+                            end_position = Some(Point2::new(column, row));
                             Tile::End
                         }
                         'S' => {
-                            reindeer_position = Some((column, row));
+                            reindeer_position = Some(Point2::new(column, row));
                             Tile::Empty
                         }
                         _ => panic!("Unsupported tile {c} at {column},{row}"),
@@ -78,8 +79,9 @@ fn dijkstra(
             ]
             .into_iter()
             .flatten()
-            .filter(|(((column, row), _), _)| {
-                map.is_valid_coord(*column, *row) && map.get(*column, *row).unwrap() != &Tile::Wall
+            .filter(|((position, _), _)| {
+                map.get_point(*position)
+                    .is_some_and(|tile| tile != &Tile::Wall)
             })
         },
     )
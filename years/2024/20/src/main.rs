@@ -1,59 +1,29 @@
-use std::collections::HashSet;
 use std::io;
-use std::iter::successors;
 
 use aoc_timing::trace::log_run;
+use grid::Grid;
+use itertools::Itertools;
 
 type Coord = (usize, usize);
 type Input = Vec<Coord>;
 type Output1 = usize;
 type Output2 = Output1;
 
+/// Returns the positions on the race track, in order from start to end.
 fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
-    let (road, start_position) = input.enumerate().fold(
-        (HashSet::new(), None),
-        |(mut road, mut start_position), (row, line)| {
-            let line = line.as_ref();
-
-            for (column, char) in line.chars().enumerate() {
-                match char {
-                    '#' => {}
-                    '.' | 'E' => {
-                        road.insert((column, row));
-                    }
-                    'S' => {
-                        road.insert((column, row));
-                        start_position = Some((column, row));
-                    }
-                    _ => panic!("Unexpected character in map: {char}"),
-                }
-            }
-
-            (road, start_position)
-        },
-    );
-
-    let start_position = start_position.unwrap();
-
-    successors(
-        Some((None as Option<Coord>, start_position)),
-        |(prev, current @ (column, row))| {
-            let mut nexts = vec![(column + 1, *row), (*column, row + 1)];
-            if column > &0 {
-                nexts.push((column - 1, *row));
-            }
-            if row > &0 {
-                nexts.push((*column, row - 1));
-            }
-
-            nexts
-                .into_iter()
-                .find(|next| prev != &Some(*next) && road.contains(next))
-                .map(|next| (Some(*current), next))
-        },
-    )
-    .map(|(_, coord)| coord)
-    .collect()
+    let map = Grid::parse_chars(input, |char| match char {
+        '#' | '.' | 'S' | 'E' => Ok(char),
+        _ => Err("Unexpected character in map"),
+    })
+    .unwrap();
+    let start_position = map.coordinates().find(|coord| map[*coord] == 'S').unwrap();
+    let distances = map.bfs_distances([start_position], |_, to| *to != '#');
+
+    map.coordinates()
+        .filter_map(|coord| distances[coord].map(|distance| (distance, coord)))
+        .sorted()
+        .map(|(_, coord)| coord)
+        .collect()
 }
 
 fn manhattan_distance(from: &Coord, to: &Coord) -> usize {
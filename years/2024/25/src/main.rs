@@ -1,6 +1,7 @@
 use std::io;
 
 use aoc_timing::trace::log_run;
+use grid::Grid;
 
 type Shape = [u8; 5];
 #[derive(Debug, Default)]
@@ -11,44 +12,30 @@ struct Input {
 type Output1 = usize;
 
 fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
-    let (mut input, shape, is_lock, start_of_shape) = input.fold(
-        (Input::default(), [0; 5], true, true),
-        |(mut input, mut shape, mut is_lock, start_of_shape), line| {
-            let line = line.as_ref();
-
-            if line.is_empty() {
-                if is_lock {
-                    input.locks.push(shape);
-                } else {
-                    input.keys.push(shape.map(|n| n - 1));
-                }
-
-                (input, [0; 5], true, true)
+    let schematics = Grid::parse_blocks(input, |c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err("Unknown schematic character"),
+    })
+    .unwrap();
+
+    schematics
+        .into_iter()
+        .fold(Input::default(), |mut input, schematic| {
+            // Locks have a filled top row and keys a filled bottom row, which don't count
+            let pins = schematic.view(0, 1, schematic.width(), schematic.height() - 2);
+            let shape = std::array::from_fn(|column| {
+                pins.column(column).filter(|filled| **filled).count() as u8
+            });
+
+            if schematic.row(0).all(|filled| *filled) {
+                input.locks.push(shape);
             } else {
-                if start_of_shape {
-                    is_lock = line.chars().all(|c| c == '#');
-                } else {
-                    line.char_indices().for_each(|(index, c)| {
-                        if c == '#' {
-                            shape[index] += 1;
-                        }
-                    });
-                }
-
-                (input, shape, is_lock, false)
+                input.keys.push(shape);
             }
-        },
-    );
-
-    if !start_of_shape {
-        if is_lock {
-            input.locks.push(shape);
-        } else {
-            input.keys.push(shape.map(|n| n - 1));
-        }
-    }
 
-    input
+            input
+        })
 }
 
 fn lock_and_key_fit(lock: &[u8; 5], key: &[u8; 5]) -> bool {
@@ -1,5 +1,4 @@
-use grid::Grid;
-use rapidhash::RapidHashSet;
+use grid::{BitGrid, Connectivity, Grid};
 
 #[derive(PartialEq, Clone, Copy)]
 pub enum Cell {
@@ -12,92 +11,46 @@ type Output1 = usize;
 type Output2 = Output1;
 
 pub fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
-    input
-        .map(|line| {
-            let line = line.as_ref();
+    Grid::parse_chars(input, |c| match c {
+        '@' => Ok(Cell::PaperRoll),
+        '.' => Ok(Cell::Empty),
+        _ => Err("Unknown cell type"),
+    })
+    .unwrap()
+}
 
-            line.chars()
-                .map(|c| match c {
-                    '@' => Cell::PaperRoll,
-                    '.' => Cell::Empty,
-                    _ => panic!("Unknown cell type {c}"),
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect()
+/// Paper rolls with fewer than four paper rolls around them.
+fn accessible(paper_rolls: &BitGrid) -> BitGrid {
+    paper_rolls
+        & &paper_rolls
+            .neighbor_counts(Connectivity::Eight)
+            .less_than(4)
 }
 
 pub fn part_1(input: &Input) -> Output1 {
-    input
-        .coordinates()
-        .map(|(column, row)| {
-            (
-                input.get(column, row).unwrap(),
-                input
-                    .get_neighbors_incl_diagonals_iter(column, row)
-                    .filter(|neighbor| input.get_coord(*neighbor).unwrap() == &Cell::PaperRoll)
-                    .count(),
-            )
-        })
-        .filter(|(cell, n)| *cell == &Cell::PaperRoll && *n < 4)
-        .count()
+    accessible(&BitGrid::from_grid(input, |cell| *cell == Cell::PaperRoll)).count_ones()
 }
 
 pub fn part_2(input: &Input) -> Output2 {
-    let mut paper_roll_coords = RapidHashSet::default();
-    paper_roll_coords.extend(
-        input
-            .coordinates()
-            .filter(|coord| input.get_coord(*coord).unwrap() == &Cell::PaperRoll),
-    );
+    let mut paper_rolls = BitGrid::from_grid(input, |cell| *cell == Cell::PaperRoll);
     let mut removed = 0;
 
     loop {
-        let to_remove = paper_roll_coords
-            .iter()
-            .copied()
-            .filter(|(column, row)| {
-                input
-                    .get_neighbors_incl_diagonals_iter(*column, *row)
-                    .filter(|neighbor| paper_roll_coords.contains(neighbor))
-                    .count()
-                    < 4
-            })
-            .collect::<Vec<_>>();
+        let to_remove = accessible(&paper_rolls);
 
-        if to_remove.is_empty() {
+        if !to_remove.any() {
             break;
         }
 
-        removed += to_remove.len();
-
-        for to_remove in to_remove.into_iter() {
-            paper_roll_coords.remove(&to_remove);
-        }
+        removed += to_remove.count_ones();
+        paper_rolls ^= &to_remove;
     }
 
     removed
 }
 
 #[cfg(test)]
+#[aoc_macros::example_tests]
 mod tests {
     use super::*;
-
-    const INPUT: &str = include_str!("test-input");
-
-    #[test]
-    fn test_part_1() {
-        let input = parse(INPUT.lines());
-        let result = part_1(&input);
-
-        assert_eq!(result, 13);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = parse(INPUT.lines());
-        let result = part_2(&input);
-
-        assert_eq!(result, 43);
-    }
 }
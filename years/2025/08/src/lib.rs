@@ -1,7 +1,8 @@
+use coord::Point3;
 use itertools::Itertools;
 use rapidhash::RapidHashSet;
 
-type JunctionBox = (i64, i64, i64);
+type JunctionBox = Point3<i64>;
 pub struct Input {
     junction_boxes: Vec<JunctionBox>,
     junction_box_pairs: Vec<(usize, usize)>,
@@ -10,20 +11,21 @@ type Output1 = usize;
 type Output2 = Output1;
 
 pub fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
-    let junction_boxes = input
+    let junction_boxes: Vec<JunctionBox> = input
         .map(|line| {
             let line = line.as_ref();
             line.split(',')
                 .map(|n| n.parse().unwrap())
                 .collect_tuple::<(_, _, _)>()
                 .unwrap()
+                .into()
         })
         .collect_vec();
 
     let junction_box_pairs = (0..junction_boxes.len())
         .tuple_combinations()
         .sorted_by_cached_key(|(a, b)| {
-            squared_euclidean_distance(junction_boxes[*a], junction_boxes[*b])
+            junction_boxes[*a].squared_euclidean_distance(&junction_boxes[*b])
         })
         .collect_vec();
 
@@ -33,18 +35,6 @@ pub fn parse<S: AsRef<str>, I: Iterator<Item = S>>(input: I) -> Input {
     }
 }
 
-#[inline]
-fn squared_euclidean_distance(a: JunctionBox, b: JunctionBox) -> i64 {
-    let x = a.0 - b.0;
-    let x = x * x;
-    let y = a.1 - b.1;
-    let y = y * y;
-    let z = a.2 - b.2;
-    let z = z * z;
-
-    x + y + z
-}
-
 fn merge_groups(group_a: &mut RapidHashSet<usize>, group_b: &mut RapidHashSet<usize>) -> bool {
     if group_b.intersection(group_a).next().is_some() {
         group_a.reserve(group_b.len());
@@ -118,7 +108,7 @@ pub fn part_2(input: &Input) -> Output2 {
         }
 
         if groups.len() == 1 && groups[0].len() == input.junction_boxes.len() {
-            return (input.junction_boxes[a].0 * input.junction_boxes[b].0)
+            return (input.junction_boxes[a].x * input.junction_boxes[b].x)
                 .try_into()
                 .unwrap();
         }
@@ -128,24 +118,7 @@ pub fn part_2(input: &Input) -> Output2 {
 }
 
 #[cfg(test)]
+#[aoc_macros::example_tests(part_1 = part_1_parameterized)]
 mod tests {
     use super::*;
-
-    const INPUT: &str = include_str!("test-input");
-
-    #[test]
-    fn test_part_1() {
-        let input = parse(INPUT.lines());
-        let result = part_1_parameterized(&input, 10);
-
-        assert_eq!(result, 40);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = parse(INPUT.lines());
-        let result = part_2(&input);
-
-        assert_eq!(result, 25272);
-    }
 }